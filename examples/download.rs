@@ -4,6 +4,12 @@
 //! ```shell
 //! cargo run --example download -- src/data_source/bank-holidays.json
 //! ```
+//!
+//! With the `icalendar` feature, bank holidays in one division can instead be saved as an iCalendar file:
+//!
+//! ```shell
+//! cargo run --example download --features icalendar -- bank-holidays.ics scotland
+//! ```
 
 use std::fs::File;
 use std::io::{BufReader, stderr, Write};
@@ -11,7 +17,7 @@ use std::path::Path;
 
 use tracing_subscriber::{EnvFilter, filter::LevelFilter, prelude::*};
 
-use govuk_bank_holidays::{PlainDate, SOURCE_URL};
+use govuk_bank_holidays::{Division, PlainDate, SOURCE_URL};
 use govuk_bank_holidays::data_source::DataSource;
 
 #[tokio::main]
@@ -33,12 +39,27 @@ async fn main() {
         .unwrap_or("download".to_owned());
 
     if let Some(path) = args.next().as_deref() {
-        if let Err(error) = download_to_path(SOURCE_URL, path).await {
+        let result = if path.ends_with(".ics") {
+            let division = match args.next().as_deref() {
+                Some(slug) => Division::all().into_iter().find(|division| division.slug() == slug),
+                None => Some(Division::EnglandAndWales),
+            };
+            match division {
+                Some(division) => download_icalendar_to_path(SOURCE_URL, path, division).await,
+                None => Err("Unknown division"),
+            }
+        } else {
+            download_to_path(SOURCE_URL, path).await
+        };
+        if let Err(error) = result {
             eprintln!("{error}");
             std::process::exit(2);
         }
     } else {
-        eprintln!("Download bank holiday information from GOV.UK data\nUsage:\n  {program} [path]   save to a JSON file");
+        eprintln!(
+            "Download bank holiday information from GOV.UK data\nUsage:\n  {program} [path]   save to a JSON file\n  \
+            {program} [path].ics [division]   save division (default england-and-wales) to an iCalendar file"
+        );
         std::process::exit(1);
     }
 }
@@ -58,9 +79,29 @@ async fn download_to_path(_url: &str, _path: &str) -> Result<(), &'static str> {
     Err("No date implementation enabled; add chrono or time feature")
 }
 
+#[cfg(all(feature = "icalendar", any(feature = "chrono", feature = "time")))]
+async fn download_icalendar_to_path(url: &str, path: &str, division: Division) -> Result<(), &'static str> {
+    #[cfg(feature = "chrono")]
+    type Date = chrono::NaiveDate;
+    #[cfg(all(not(feature = "chrono"), feature = "time"))]
+    type Date = time::Date;
+
+    let data = download::<Date>(url).await?;
+    std::fs::write(path, data.to_icalendar(division))
+        .map_err(|error| {
+            tracing::error!("{error}");
+            "Could not write to file"
+        })
+}
+
+#[cfg(not(all(feature = "icalendar", any(feature = "chrono", feature = "time"))))]
+async fn download_icalendar_to_path(_url: &str, _path: &str, _division: Division) -> Result<(), &'static str> {
+    Err("iCalendar export not enabled; add icalendar feature and either chrono or time feature")
+}
+
 #[cfg(any(feature = "chrono", feature = "time"))]
-async fn download_to_path_impl<Date: PlainDate>(url: &str, path: &str) -> Result<(), &'static str> {
-    let mut data = reqwest::get(url)
+async fn download<Date: PlainDate>(url: &str) -> Result<DataSource<Date>, &'static str> {
+    reqwest::get(url)
         .await
         .map_err(|error| {
             tracing::error!("{error}");
@@ -71,7 +112,12 @@ async fn download_to_path_impl<Date: PlainDate>(url: &str, path: &str) -> Result
         .map_err(|error| {
             tracing::error!("{error}");
             "Could not deserialise data"
-        })?;
+        })
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+async fn download_to_path_impl<Date: PlainDate>(url: &str, path: &str) -> Result<(), &'static str> {
+    let mut data = download::<Date>(url).await?;
 
     let path = Path::new(path);
     if path.is_file() {
@@ -0,0 +1,72 @@
+//! Tool to generate historical bank holidays, before those published by GOV.UK,
+//! from the rules in force in each year and the known special bank holidays.
+//! Used to create the embedded file behind the `historical` feature in this library.
+//!
+//! ```shell
+//! cargo run --example historical -- src/data_source/bank-holidays-historical.json
+//! ```
+
+use std::fs::File;
+use std::io::Write;
+
+use govuk_bank_holidays::PlainDate;
+use govuk_bank_holidays::data_source::{Computed, Special};
+
+/// Years before those in the embedded GOV.UK data.
+/// Starts once the Banking and Financial Dealings Act 1971 schedule had been extended by royal proclamation
+/// to New Year’s Day (1974), Boxing Day in Scotland (1974) and the Early May bank holiday (1978),
+/// since the rules in earlier years varied locally and cannot be checked against a primary source.
+const YEARS: std::ops::RangeInclusive<i32> = 1978..=2011;
+
+fn main() {
+    let mut args = std::env::args();
+    let program = args.next()
+        .and_then(|program| {
+            program.rsplit_once('/')
+                .map(|(_path, program)| program.to_owned())
+        })
+        .unwrap_or("historical".to_owned());
+
+    if let Some(path) = args.next().as_deref() {
+        if let Err(error) = generate_to_path(path) {
+            eprintln!("{error}");
+            std::process::exit(2);
+        }
+    } else {
+        eprintln!("Generate historical bank holidays\nUsage:\n  {program} [path]   save to a JSON file");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn generate_to_path(path: &str) -> Result<(), &'static str> {
+    generate_to_path_impl::<chrono::NaiveDate>(path)
+}
+
+#[cfg(all(not(feature = "chrono"), feature = "time"))]
+fn generate_to_path(path: &str) -> Result<(), &'static str> {
+    generate_to_path_impl::<time::Date>(path)
+}
+
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+fn generate_to_path(_path: &str) -> Result<(), &'static str> {
+    Err("No date implementation enabled; add chrono or time feature")
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn generate_to_path_impl<Date: PlainDate>(path: &str) -> Result<(), &'static str> {
+    // NB: bank holidays keep their provenance: generated ones are predicted and special ones are manual
+    let mut data = Computed::<Date>::new(YEARS)
+        .computed_data_source()
+        .map_err(|_| "Could not compute bank holidays")?;
+    Special::default()
+        .apply(&mut data)
+        .map_err(|_| "Could not apply special bank holidays")?;
+
+    let mut file = File::create(path)
+        .map_err(|_| "Could not create file")?;
+    serde_json::to_writer_pretty(&mut file, &data)
+        .map_err(|_| "Could not serialise to file")?;
+    file.write_all(b"\n")
+        .map_err(|_| "Could not write to file")
+}
@@ -14,6 +14,30 @@ pub struct BankHoliday<Date: PlainDate> {
     title: String,
     notes: String,
     bunting: bool,
+    #[serde(default, skip_serializing_if = "Provenance::is_official")]
+    provenance: Provenance,
+}
+
+/// Where details of a bank holiday came from.
+/// Only non-official bank holidays have this serialised so the GOV.UK format is preserved.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Provenance {
+    /// Published by GOV.UK.
+    #[default]
+    Official,
+    /// Generated from rules, see [`Computed`](crate::data_source::Computed).
+    Predicted,
+    /// Added by other means.
+    Manual,
+}
+
+impl Provenance {
+    /// Whether bank holiday was published by GOV.UK.
+    #[inline]
+    pub fn is_official(&self) -> bool {
+        matches!(self, Provenance::Official)
+    }
 }
 
 impl<Date: PlainDate> BankHoliday<Date> {
@@ -31,9 +55,22 @@ impl<Date: PlainDate> BankHoliday<Date> {
             title,
             notes,
             bunting: false,
+            provenance: Provenance::Official,
         }
     }
 
+    /// Same bank holiday with given bunting.
+    #[inline]
+    pub fn with_bunting(self, bunting: bool) -> Self {
+        Self { bunting, ..self }
+    }
+
+    /// Same bank holiday with given provenance.
+    #[inline]
+    pub fn with_provenance(self, provenance: Provenance) -> Self {
+        Self { provenance, ..self }
+    }
+
     /// Convert bank holiday into underlying date.
     #[inline]
     pub fn into_date(self) -> Date {
@@ -63,6 +100,19 @@ impl<Date: PlainDate> BankHoliday<Date> {
     pub fn bunting(&self) -> bool {
         self.bunting
     }
+
+    /// Whether this is a one-off bank holiday or a regular one that was moved, both by royal proclamation,
+    /// according to the built-in list in [`Special`](crate::data_source::Special).
+    #[inline]
+    pub fn is_special(&self) -> bool {
+        crate::data_source::is_special(self.date(), self.title())
+    }
+
+    /// Where details of this bank holiday came from; official unless otherwise specified.
+    #[inline]
+    pub fn provenance(&self) -> Provenance {
+        self.provenance
+    }
 }
 
 impl<Date: PlainDate> AsRef<Date> for BankHoliday<Date> {
@@ -120,6 +170,8 @@ mod tests {
         assert_eq!(boxing_day.into_date().as_components(), (2022, 12, 26));
         assert_eq!(christmas.notes(), "Substitute day");
         assert!(!christmas.bunting());
+        assert!(!christmas.is_special());
+        assert!(christmas.clone().with_bunting(true).bunting());
         assert_eq!(&format!("{:?}", christmas), "2022-12-27 - Christmas Day (Substitute day)");
 
         // language=json
@@ -141,6 +193,17 @@ mod tests {
         assert_eq!(christmas_json.get("title"), Some(&json!("Christmas Day")));
         assert_eq!(christmas_json.get("notes"), Some(&json!("Substitute day")));
         assert_eq!(christmas_json.get("bunting"), Some(&json!(false)));
+        assert_eq!(christmas_json.get("provenance"), None);
+
+        let christmas = christmas.with_provenance(Provenance::Predicted);
+        assert_eq!(christmas.provenance(), Provenance::Predicted);
+        assert_ne!(christmas, bank_holiday);
+        let christmas_json = serde_json::to_string(&christmas)
+            .expect("failed to serialise bank holiday");
+        assert!(christmas_json.contains(r#""provenance":"predicted""#));
+        let deserialised_christmas = serde_json::from_str::<BankHoliday<Date>>(&christmas_json)
+            .expect("failed to deserialise bank holiday");
+        assert_eq!(deserialised_christmas, christmas);
     }
 
     #[cfg(feature = "chrono")]
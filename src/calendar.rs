@@ -1,8 +1,12 @@
 use std::collections::{HashMap, HashSet};
 use std::iter::FusedIterator;
+use std::slice;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant, SystemTime};
 
 use crate::{BankHoliday, Division, Error, MonToFriWorkDays, PlainDate, WorkDays};
 use crate::data_source::{Cached, DataSource, LoadDataSource, Reqwest};
+use crate::dates::day_number;
 
 /// Calendar of known bank holidays.
 ///
@@ -11,7 +15,7 @@ use crate::data_source::{Cached, DataSource, LoadDataSource, Reqwest};
 /// *all* divisions if `None` is provided.
 pub struct BankHolidayCalendar<Date: PlainDate, W: WorkDays<Date>> {
     holiday_map: HashMap<Division, Vec<BankHoliday<Date>>>,
-    holidays_common_to_all_divisions: HashSet<Date>,
+    holidays_common_to_all_divisions: Vec<BankHoliday<Date>>,
     work_days: W,
 }
 
@@ -23,6 +27,13 @@ impl<Date: PlainDate> BankHolidayCalendar<Date, MonToFriWorkDays> {
         Self::load_with(MonToFriWorkDays).await
     }
 
+    /// Load UK bank holidays like [`BankHolidayCalendar::load`], also reporting where they came from
+    /// and whether cached/embedded data had to be used.
+    #[inline]
+    pub async fn load_detailed() -> (Self, LoadReport) {
+        Self::load_detailed_with(MonToFriWorkDays).await
+    }
+
     /// Build from cached/embedded data, using a Monday to Friday work week.
     #[inline]
     pub fn cached() -> Self {
@@ -39,13 +50,42 @@ impl<Date: PlainDate> BankHolidayCalendar<Date, MonToFriWorkDays> {
 impl<Date: PlainDate, W: WorkDays<Date>> BankHolidayCalendar<Date, W> {
     /// Load UK bank holidays from [GOV.UK](https://www.gov.uk/bank-holidays), falling back to cached/embedded data,
     /// using given [`WorkDays`].
+    #[inline]
     pub async fn load_with(work_days: W) -> Self {
-        let data_source = Reqwest::default().load_data_source().await.unwrap_or_else(|error| {
-            tracing::error!("Failed to load bank holidays: {error}");
-            tracing::info!("Falling back to cached calendar data");
-            Cached::default().cached_data_source()
-        });
-        Self::new(data_source, work_days)
+        let (calendar, _report) = Self::load_detailed_with(work_days).await;
+        calendar
+    }
+
+    /// Load UK bank holidays like [`BankHolidayCalendar::load_with`], also reporting where they came from
+    /// and whether cached/embedded data had to be used.
+    #[inline]
+    pub async fn load_detailed_with(work_days: W) -> (Self, LoadReport) {
+        Self::load_or_cached(&Reqwest::default(), work_days).await
+    }
+
+    /// Private method to load from given source, falling back to cached/embedded data, and report which was used.
+    pub(crate) async fn load_or_cached<T: LoadDataSource<Date>>(loader: &T, work_days: W) -> (Self, LoadReport) {
+        let started = Instant::now();
+        let (data_source, source, fallback_error) = match loader.load_named_data_source().await {
+            Ok((data_source, source)) => (data_source, source, None),
+            Err(error) => {
+                tracing::error!("Failed to load bank holidays: {error}");
+                tracing::info!("Falling back to cached calendar data");
+                let cached = Cached::default();
+                let source = LoadDataSource::<Date>::name(&cached);
+                (cached.cached_data_source(), source, Some(error))
+            }
+        };
+        let duration = started.elapsed();
+        let calendar = Self::new(data_source, work_days);
+        let report = LoadReport {
+            source,
+            fallback_error,
+            loaded_at: SystemTime::now(),
+            duration,
+            coverage: calendar.coverage(None),
+        };
+        (calendar, report)
     }
 
     /// Build from cached/embedded data, using given [`WorkDays`].
@@ -60,10 +100,19 @@ impl<Date: PlainDate, W: WorkDays<Date>> BankHolidayCalendar<Date, W> {
             .map(|data_source| Self::new(data_source, work_days))
     }
 
-    /// Private method to build a calendar from a [`DataSource`] and given [`WorkDays`].
-    fn new(data_source: DataSource<Date>, work_days: W) -> Self {
+    /// Known bank holidays in each division, in date order.
+    #[cfg(feature = "shared")]
+    #[inline]
+    pub(crate) fn holiday_map(&self) -> &HashMap<Division, Vec<BankHoliday<Date>>> {
+        &self.holiday_map
+    }
+
+    /// Internal method to build a calendar from a [`DataSource`] and given [`WorkDays`].
+    pub(crate) fn new(mut data_source: DataSource<Date>, work_days: W) -> Self {
+        // holidays are kept in date order so that ranges can be found with a binary search
+        data_source.sort();
         let holiday_map = data_source.into_inner();
-        let holidays_common_to_all_divisions = holiday_map.values()
+        let common_dates = holiday_map.values()
             .fold(None, |mut common: Option<HashSet<&Date>>, bank_holidays| {
                 let dates: HashSet<_> = bank_holidays.iter()
                     .map(|bank_holiday| bank_holiday.date())
@@ -79,44 +128,91 @@ impl<Date: PlainDate, W: WorkDays<Date>> BankHolidayCalendar<Date, W> {
                 tracing::warn!("Empty bank holiday calendar");
                 HashSet::new()
             });
-        let holidays_common_to_all_divisions = holidays_common_to_all_divisions.into_iter().cloned().collect();
+        // common holidays take their titles from England and Wales where possible
+        let holidays_common_to_all_divisions = holiday_map.get(&Division::EnglandAndWales)
+            .or_else(|| holiday_map.values().next())
+            .map(|bank_holidays| {
+                bank_holidays.iter()
+                    .filter(|bank_holiday| common_dates.contains(bank_holiday.date()))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
         BankHolidayCalendar { holiday_map, holidays_common_to_all_divisions, work_days }
     }
 
+    /// Private method to get known holidays, in date order,
+    /// in given `division` of the UK or only those common to all divisions.
+    fn holiday_slice(&self, division: Option<Division>) -> &[BankHoliday<Date>] {
+        match division {
+            Some(division) => self.holiday_map.get(&division).map(Vec::as_slice).unwrap_or_default(),
+            None => &self.holidays_common_to_all_divisions,
+        }
+    }
+
     /// Get all known holidays in given `division` of the UK or only those common to all divisions.
     pub fn holidays(&self, division: Option<Division>) -> Vec<&BankHoliday<Date>> {
-        let mut holidays = Vec::new();
-        if let Some(division) = division {
-            if let Some(bank_holidays) = self.holiday_map.get(&division) {
-                holidays.extend(bank_holidays);
-            }
-        } else {
-            let bank_holidays = self.holiday_map.get(&Division::EnglandAndWales)
-                .or_else(|| self.holiday_map.values().next());
-            if let Some(bank_holidays) = bank_holidays {
-                holidays.extend(
-                    bank_holidays.iter()
-                        .filter(|bank_holiday| {
-                            self.holidays_common_to_all_divisions.contains(bank_holiday.date())
-                        })
-                );
-            }
-        }
-        holidays
+        self.holiday_slice(division).iter().collect()
     }
 
     /// Checks whether `date` is a bank holiday in given `division` or common to all divisions.
     pub fn is_holiday(&self, date: &Date, division: Option<Division>) -> bool {
-        if let Some(division) = division {
-            self.holiday_map.get(&division)
-                .map(|bank_holidays| {
-                    bank_holidays.iter()
-                        .any(|bank_holiday| bank_holiday.date() == date)
-                })
-                .unwrap_or(false)
+        let bank_holidays = self.holiday_slice(division);
+        bank_holidays.binary_search_by(|bank_holiday| bank_holiday.date().cmp(date)).is_ok()
+    }
+
+    /// Range of years with known bank holidays in given `division` or, for `None`, the years known in all divisions.
+    /// Returns `None` if there are no known bank holidays.
+    ///
+    /// NB: Dates outside this range are treated as work days by most methods, which is likely wrong.
+    /// Use [`BankHolidayCalendar::lookup`] or “try” methods like [`BankHolidayCalendar::try_is_work_day`] to detect this.
+    pub fn coverage(&self, division: Option<Division>) -> Option<RangeInclusive<i32>> {
+        let year_range = |bank_holidays: &Vec<BankHoliday<Date>>| {
+            let first = bank_holidays.first()?;
+            let last = bank_holidays.last()?;
+            Some((first.date().year(), last.date().year()))
+        };
+        let (first_year, last_year) = if let Some(division) = division {
+            self.holiday_map.get(&division).and_then(year_range)?
         } else {
-            self.holidays_common_to_all_divisions.contains(date)
+            self.holiday_map.values()
+                .map(year_range)
+                .reduce(|common, year_range| {
+                    let (common_first_year, common_last_year) = common?;
+                    let (first_year, last_year) = year_range?;
+                    Some((common_first_year.max(first_year), common_last_year.min(last_year)))
+                })
+                .flatten()?
+        };
+        (first_year <= last_year).then_some(first_year..=last_year)
+    }
+
+    /// Private method to check whether `date` is within the years covered by known bank holidays.
+    fn covers(&self, date: &Date, division: Option<Division>) -> bool {
+        self.coverage(division)
+            .is_some_and(|years| years.contains(&date.year()))
+    }
+
+    /// Look up `date` in given `division` or common to all divisions, distinguishing dates that are not bank holidays
+    /// from those outside the years covered by known bank holidays.
+    pub fn lookup(&self, date: &Date, division: Option<Division>) -> HolidayLookup<'_, Date> {
+        if !self.covers(date, division) {
+            return HolidayLookup::Unknown;
         }
+        match self.holidays_between(date, date, division).first() {
+            Some(bank_holiday) => HolidayLookup::Holiday(bank_holiday),
+            None => HolidayLookup::NotHoliday,
+        }
+    }
+
+    /// Private method to find all known holidays from `first` to `last` date, inclusive,
+    /// in given `division` of the UK or only those common to all divisions.
+    /// Relies on holidays being sorted by date.
+    fn holidays_between(&self, first: &Date, last: &Date, division: Option<Division>) -> &[BankHoliday<Date>] {
+        let bank_holidays = self.holiday_slice(division);
+        let start = bank_holidays.partition_point(|bank_holiday| bank_holiday.date() < first);
+        let end = bank_holidays.partition_point(|bank_holiday| bank_holiday.date() <= last);
+        &bank_holidays[start..end.max(start)]
     }
 
     /// Checks whether `date` is a work day in given `division` or common to all divisions.
@@ -124,6 +220,16 @@ impl<Date: PlainDate, W: WorkDays<Date>> BankHolidayCalendar<Date, W> {
         self.work_days.is_work_day(date) && !self.is_holiday(date, division)
     }
 
+    /// Checks whether `date` is a work day in given `division` or common to all divisions,
+    /// returning [`Error::OutsideCoverage`] if `date` is outside the years covered by known bank holidays.
+    pub fn try_is_work_day(&self, date: &Date, division: Option<Division>) -> Result<bool, Error> {
+        if self.covers(date, division) {
+            Ok(self.is_work_day(date, division))
+        } else {
+            Err(Error::OutsideCoverage)
+        }
+    }
+
     /// Get [`WorkDays`] implementation.
     #[inline]
     pub fn work_days(&self) -> &W {
@@ -136,61 +242,340 @@ impl<Date: PlainDate, W: WorkDays<Date>> BankHolidayCalendar<Date, W> {
         &mut self.work_days
     }
 
-    /// Iterate over all known bank holidays _after_ a `date` in given `division` or common to all divisions.
-    /// Iterator yields [`&BankHoliday`](BankHoliday).
+    /// Iterate over all known bank holidays _after_ a `date` in given `division` or common to all divisions,
+    /// nearest first. Iterator yields [`&BankHoliday`](BankHoliday).
     pub fn iter_holidays_after(&self, date: &Date, division: Option<Division>) -> HolidayIter<'_, Date> {
-        let holidays = self.holidays(division)
-            .drain(..)
-            .rev()
-            .filter(|bank_holiday| bank_holiday.date() > date)
-            .collect();
-        HolidayIter { holidays }
+        let bank_holidays = self.holiday_slice(division);
+        let start = bank_holidays.partition_point(|bank_holiday| bank_holiday.date() <= date);
+        HolidayIter { holidays: bank_holidays[start..].iter(), forward: true }
     }
 
-    /// Iterate over all known bank holidays _before_ a `date` in given `division` or common to all divisions.
-    /// Iterator yields [`&BankHoliday`](BankHoliday).
+    /// Iterate over all known bank holidays _before_ a `date` in given `division` or common to all divisions,
+    /// nearest first. Iterator yields [`&BankHoliday`](BankHoliday).
     pub fn iter_holidays_before(&self, date: &Date, division: Option<Division>) -> HolidayIter<'_, Date> {
-        let holidays = self.holidays(division)
-            .drain(..)
-            .filter(|bank_holiday| bank_holiday.date() < date)
-            .collect();
-        HolidayIter { holidays }
+        let bank_holidays = self.holiday_slice(division);
+        let end = bank_holidays.partition_point(|bank_holiday| bank_holiday.date() < date);
+        HolidayIter { holidays: bank_holidays[..end].iter(), forward: false }
     }
 
     /// Iterate over all work days _after_ a `date`, skipping bank holidays in given `division`
     /// or common to all divisions.
     /// Iterator yields [`PlainDate`] implementation.
     ///
-    /// NB: this is an infinite iterator.
+    /// NB: this iterator only ends if no work day is found within its search limit,
+    /// see [`WorkDayIter::with_search_limit`].
     #[inline]
     pub fn iter_work_days_after(&self, date: Date, division: Option<Division>) -> WorkDayIter<'_, Date, W> {
-        WorkDayIter { calendar: self, date, division, forward: true }
+        WorkDayIter::new(self, date, division, true)
     }
 
     /// Iterate over all work days _before_ a `date`, skipping bank holidays in given `division`
     /// or common to all divisions.
     /// Iterator yields [`PlainDate`] implementation.
     ///
-    /// NB: this is an infinite iterator.
+    /// NB: this iterator only ends if no work day is found within its search limit,
+    /// see [`WorkDayIter::with_search_limit`].
     #[inline]
     pub fn iter_work_days_before(&self, date: Date, division: Option<Division>) -> WorkDayIter<'_, Date, W> {
-        WorkDayIter { calendar: self, date, division, forward: false }
+        WorkDayIter::new(self, date, division, false)
+    }
+
+    /// Iterate over work days from `start` to `end` dates, skipping bank holidays in given `division`
+    /// or common to all divisions. `endpoints` determines whether `start` and `end` themselves could be included.
+    /// Iterator yields [`PlainDate`] implementation in date order, or in reverse from the back.
+    pub fn iter_work_days_between(
+        &self,
+        start: &Date,
+        end: &Date,
+        endpoints: Endpoints,
+        division: Option<Division>,
+    ) -> WorkDayRangeIter<'_, Date, W> {
+        let first = if endpoints.includes_start() { start.clone() } else { start.next_day() };
+        let last = if endpoints.includes_end() { end.clone() } else { end.previous_day() };
+        let finished = first > last;
+        WorkDayRangeIter { calendar: self, first, last, division, finished }
     }
+
+    /// Find the date that is `days` work days _after_ a `date` (or _before_ it if `days` is negative),
+    /// skipping bank holidays in given `division` or common to all divisions.
+    ///
+    /// NB: `date` itself is never counted so it need not be a work day; adding 1 work day to a Saturday gives
+    /// the following Monday (unless that is a bank holiday). Adding 0 work days returns `date` unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If there are no work days within the [default search limit](WorkDayIter::DEFAULT_SEARCH_LIMIT),
+    /// which suggests that [`WorkDays`] is misconfigured.
+    pub fn add_work_days(&self, date: Date, days: i32, division: Option<Division>) -> Date {
+        self.step_work_days(date, days.unsigned_abs(), days >= 0, division)
+    }
+
+    /// Find the date that is `days` work days _after_ a `date` (or _before_ it if `days` is negative),
+    /// as [`BankHolidayCalendar::add_work_days`] does, but returning [`Error::OutsideCoverage`]
+    /// if any date along the way is outside the years covered by known bank holidays
+    /// or [`Error::NoWorkDay`] instead of panicking if there are no work days within the search limit.
+    pub fn try_add_work_days(&self, date: Date, days: i32, division: Option<Division>) -> Result<Date, Error> {
+        if !self.covers(&date, division) {
+            return Err(Error::OutsideCoverage);
+        }
+        // coverage is a continuous range so all dates along the way are covered if the result is
+        let date = self.try_step_work_days(date, days.unsigned_abs(), days >= 0, division)
+            .ok_or(Error::NoWorkDay)?;
+        if self.covers(&date, division) {
+            Ok(date)
+        } else {
+            Err(Error::OutsideCoverage)
+        }
+    }
+
+    /// Find the date that is `days` work days _before_ a `date` (or _after_ it if `days` is negative),
+    /// skipping bank holidays in given `division` or common to all divisions.
+    ///
+    /// NB: `date` itself is never counted, as with [`BankHolidayCalendar::add_work_days`].
+    pub fn subtract_work_days(&self, date: Date, days: i32, division: Option<Division>) -> Date {
+        self.step_work_days(date, days.unsigned_abs(), days < 0, division)
+    }
+
+    /// Count work days between `start` and `end` dates, skipping bank holidays in given `division`
+    /// or common to all divisions. `endpoints` determines whether `start` and `end` are themselves counted.
+    /// Returns zero if `end` precedes `start`.
+    ///
+    /// NB: this does not check each day in turn if the [`WorkDays`] implementation can count them directly,
+    /// as [`MonToFriWorkDays`] does.
+    pub fn count_work_days_between(
+        &self,
+        start: &Date,
+        end: &Date,
+        endpoints: Endpoints,
+        division: Option<Division>,
+    ) -> usize {
+        let first = if endpoints.includes_start() { start.clone() } else { start.next_day() };
+        let last = if endpoints.includes_end() { end.clone() } else { end.previous_day() };
+        if first > last {
+            return 0;
+        }
+        let work_days = self.work_days.count_work_days(&first, &last);
+        let mut previous_date = None;
+        let holidays_on_work_days = self.holidays_between(&first, &last, division).iter()
+            .map(BankHoliday::date)
+            .filter(|date| {
+                // several bank holidays could fall on the same date
+                let is_repeated = previous_date == Some(*date);
+                previous_date = Some(*date);
+                !is_repeated && self.work_days.is_work_day(date)
+            })
+            .count();
+        work_days - holidays_on_work_days
+    }
+
+    /// Adjust a `date` that is not a work day using given [`BusinessDayConvention`], skipping bank holidays
+    /// in given `division` or common to all divisions. Work days are returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If there are no work days within the [default search limit](WorkDayIter::DEFAULT_SEARCH_LIMIT),
+    /// which suggests that [`WorkDays`] is misconfigured; see [`BankHolidayCalendar::try_adjust`].
+    pub fn adjust(&self, date: &Date, convention: BusinessDayConvention, division: Option<Division>) -> Date {
+        self.try_adjust(date, convention, division)
+            .expect("work day should be found within search limit")
+    }
+
+    /// Adjust a `date` that is not a work day using given [`BusinessDayConvention`],
+    /// as [`BankHolidayCalendar::adjust`] does, but returning [`Error::NoWorkDay`] instead of panicking
+    /// if there are no work days within the search limit.
+    pub fn try_adjust(
+        &self,
+        date: &Date,
+        convention: BusinessDayConvention,
+        division: Option<Division>,
+    ) -> Result<Date, Error> {
+        if self.is_work_day(date, division) {
+            return Ok(date.clone());
+        }
+        let following = || {
+            self.iter_work_days_after(date.clone(), division).next()
+                .ok_or(Error::NoWorkDay)
+        };
+        let preceding = || {
+            self.iter_work_days_before(date.clone(), division).next()
+                .ok_or(Error::NoWorkDay)
+        };
+        match convention {
+            BusinessDayConvention::Following => following(),
+            BusinessDayConvention::ModifiedFollowing => {
+                let adjusted = following()?;
+                if adjusted.month() == date.month() { Ok(adjusted) } else { preceding() }
+            }
+            BusinessDayConvention::Preceding => preceding(),
+            BusinessDayConvention::ModifiedPreceding => {
+                let adjusted = preceding()?;
+                if adjusted.month() == date.month() { Ok(adjusted) } else { following() }
+            }
+            BusinessDayConvention::Unadjusted => Ok(date.clone()),
+        }
+    }
+
+    /// Private method to move `count` work days forwards or backwards from a `date`.
+    fn step_work_days(&self, date: Date, count: u32, forward: bool, division: Option<Division>) -> Date {
+        self.try_step_work_days(date, count, forward, division)
+            .expect("work day should be found within search limit")
+    }
+
+    /// Private method to move `count` work days forwards or backwards from a `date`,
+    /// returning `None` if a work day is not found within the search limit.
+    fn try_step_work_days(&self, date: Date, count: u32, forward: bool, division: Option<Division>) -> Option<Date> {
+        let Some(skip) = (count as usize).checked_sub(1) else {
+            return Some(date);
+        };
+        let mut work_days = if forward {
+            self.iter_work_days_after(date, division)
+        } else {
+            self.iter_work_days_before(date, division)
+        };
+        work_days.nth(skip)
+    }
+}
+
+/// Result of looking up a date using [`BankHolidayCalendar::lookup`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum HolidayLookup<'a, Date: PlainDate> {
+    /// Date is a known bank holiday.
+    Holiday(&'a BankHoliday<Date>),
+    /// Date is not a bank holiday.
+    NotHoliday,
+    /// Date is outside the years covered by known bank holidays so it is not known whether it is a bank holiday.
+    Unknown,
 }
 
+/// Details of how a calendar was loaded by [`BankHolidayCalendar::load_detailed`].
+/// Useful for monitoring whether stale cached/embedded data is in use.
+#[derive(Debug)]
+pub struct LoadReport {
+    source: String,
+    fallback_error: Option<Error>,
+    loaded_at: SystemTime,
+    duration: Duration,
+    coverage: Option<RangeInclusive<i32>>,
+}
+
+impl LoadReport {
+    /// Name of the source that bank holidays were loaded from.
+    #[inline]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether loading failed so cached/embedded data was used instead.
+    #[inline]
+    pub fn is_fallback(&self) -> bool {
+        self.fallback_error.is_some()
+    }
+
+    /// Error that caused cached/embedded data to be used instead, if any.
+    #[inline]
+    pub fn fallback_error(&self) -> Option<&Error> {
+        self.fallback_error.as_ref()
+    }
+
+    /// When loading finished.
+    #[inline]
+    pub fn loaded_at(&self) -> SystemTime {
+        self.loaded_at
+    }
+
+    /// How long loading took, including any failed attempt.
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Range of years with known bank holidays in all divisions of the loaded calendar,
+    /// see [`BankHolidayCalendar::coverage`].
+    #[inline]
+    pub fn coverage(&self) -> Option<RangeInclusive<i32>> {
+        self.coverage.clone()
+    }
+}
+
+/// Whether the `start` and `end` dates of a range are themselves included,
+/// as used by [`BankHolidayCalendar::count_work_days_between`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Endpoints {
+    /// Both `start` and `end` are included.
+    Inclusive,
+    /// Neither `start` nor `end` is included.
+    Exclusive,
+    /// Only `start` is included.
+    IncludeStart,
+    /// Only `end` is included.
+    IncludeEnd,
+}
+
+impl Endpoints {
+    #[inline]
+    fn includes_start(self) -> bool {
+        matches!(self, Endpoints::Inclusive | Endpoints::IncludeStart)
+    }
+
+    #[inline]
+    fn includes_end(self) -> bool {
+        matches!(self, Endpoints::Inclusive | Endpoints::IncludeEnd)
+    }
+}
+
+/// Conventions for rolling a date that is not a work day onto one that is,
+/// as used by [`BankHolidayCalendar::adjust`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BusinessDayConvention {
+    /// The following work day.
+    Following,
+    /// The following work day, unless that falls in the next month, in which case the preceding work day.
+    ModifiedFollowing,
+    /// The preceding work day.
+    Preceding,
+    /// The preceding work day, unless that falls in the previous month, in which case the following work day.
+    ModifiedPreceding,
+    /// The date is left unchanged.
+    Unadjusted,
+}
+
+/// Iterator over a range of known bank holidays, moving away from a date;
+/// see [`BankHolidayCalendar::iter_holidays_after`] and [`BankHolidayCalendar::iter_holidays_before`].
+/// Iterating from the back yields the furthest bank holidays first.
+#[derive(Debug, Clone)]
 pub struct HolidayIter<'a, Date: PlainDate> {
-    holidays: Vec<&'a BankHoliday<Date>>,
+    holidays: slice::Iter<'a, BankHoliday<Date>>,
+    forward: bool,
 }
 
 impl<'a, Date: PlainDate> Iterator for HolidayIter<'a, Date> {
     type Item = &'a BankHoliday<Date>;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.holidays.pop()
+        if self.forward { self.holidays.next() } else { self.holidays.next_back() }
     }
 
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.forward { self.holidays.nth(n) } else { self.holidays.nth_back(n) }
+    }
+
+    #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.holidays.len(), Some(self.holidays.len()))
+        self.holidays.size_hint()
+    }
+}
+
+impl<'a, Date: PlainDate> DoubleEndedIterator for HolidayIter<'a, Date> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.forward { self.holidays.next_back() } else { self.holidays.next() }
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if self.forward { self.holidays.nth_back(n) } else { self.holidays.nth(n) }
     }
 }
 
@@ -198,20 +583,40 @@ impl<'a, Date: PlainDate> ExactSizeIterator for HolidayIter<'a, Date> {}
 
 impl<'a, Date: PlainDate> FusedIterator for HolidayIter<'a, Date> {}
 
+/// Iterator over work days moving away from a date;
+/// see [`BankHolidayCalendar::iter_work_days_after`] and [`BankHolidayCalendar::iter_work_days_before`].
 pub struct WorkDayIter<'a, Date: PlainDate, W: WorkDays<Date>> {
     calendar: &'a BankHolidayCalendar<Date, W>,
     date: Date,
     division: Option<Division>,
     forward: bool,
+    search_limit: u32,
+    finished: bool,
 }
 
 impl<'a, Date: PlainDate, W: WorkDays<Date>> WorkDayIter<'a, Date, W> {
+    /// Number of days to search for each work day before the iterator ends, unless changed.
+    pub const DEFAULT_SEARCH_LIMIT: u32 = 366;
+
+    #[inline]
+    fn new(calendar: &'a BankHolidayCalendar<Date, W>, date: Date, division: Option<Division>, forward: bool) -> Self {
+        Self { calendar, date, division, forward, search_limit: Self::DEFAULT_SEARCH_LIMIT, finished: false }
+    }
+
+    /// Set the number of days to search for each work day; the iterator ends if none is found within this limit.
+    /// Stops the iterator running forever if [`WorkDays`] has no more work days, eg. if misconfigured.
+    #[inline]
+    pub fn with_search_limit(self, search_limit: u32) -> Self {
+        Self { search_limit, ..self }
+    }
+
     fn advance_date(&mut self) {
-        if self.forward {
-            self.date = self.date.next_day()
+        let work_days = &self.calendar.work_days;
+        self.date = if self.forward {
+            next_work_day_hint(work_days, &self.date)
         } else {
-            self.date = self.date.previous_day()
-        }
+            previous_work_day_hint(work_days, &self.date)
+        };
     }
 }
 
@@ -219,10 +624,83 @@ impl<'a, Date: PlainDate, W: WorkDays<Date>> Iterator for WorkDayIter<'a, Date,
     type Item = Date;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.advance_date();
-        while !self.calendar.is_work_day(&self.date, self.division) {
+        if self.finished {
+            return None;
+        }
+        let start = day_number(&self.date);
+        loop {
             self.advance_date();
+            if (day_number(&self.date) - start).unsigned_abs() > u64::from(self.search_limit) {
+                tracing::warn!("No work day found within {} days of {:?}", self.search_limit, self.date);
+                self.finished = true;
+                return None;
+            }
+            if self.calendar.is_work_day(&self.date, self.division) {
+                return Some(self.date.clone());
+            }
+        }
+    }
+}
+
+impl<'a, Date: PlainDate, W: WorkDays<Date>> FusedIterator for WorkDayIter<'a, Date, W> {}
+
+/// Iterator over work days between two dates; see [`BankHolidayCalendar::iter_work_days_between`].
+pub struct WorkDayRangeIter<'a, Date: PlainDate, W: WorkDays<Date>> {
+    calendar: &'a BankHolidayCalendar<Date, W>,
+    /// First date not yet considered.
+    first: Date,
+    /// Last date not yet considered.
+    last: Date,
+    division: Option<Division>,
+    finished: bool,
+}
+
+impl<'a, Date: PlainDate, W: WorkDays<Date>> Iterator for WorkDayRangeIter<'a, Date, W> {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let date = self.first.clone();
+            if date == self.last {
+                self.finished = true;
+            } else {
+                self.first = next_work_day_hint(&self.calendar.work_days, &date).min(self.last.clone());
+            }
+            if self.calendar.is_work_day(&date, self.division) {
+                return Some(date);
+            }
         }
-        Some(self.date.clone())
+        None
     }
 }
+
+impl<'a, Date: PlainDate, W: WorkDays<Date>> DoubleEndedIterator for WorkDayRangeIter<'a, Date, W> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let date = self.last.clone();
+            if date == self.first {
+                self.finished = true;
+            } else {
+                self.last = previous_work_day_hint(&self.calendar.work_days, &date).max(self.first.clone());
+            }
+            if self.calendar.is_work_day(&date, self.division) {
+                return Some(date);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, Date: PlainDate, W: WorkDays<Date>> FusedIterator for WorkDayRangeIter<'a, Date, W> {}
+
+/// Date after `date` from which to continue looking for work days;
+/// at least the next day even if the [`WorkDays`] hint does not move forwards, so iterators always progress.
+fn next_work_day_hint<Date: PlainDate, W: WorkDays<Date>>(work_days: &W, date: &Date) -> Date {
+    work_days.next_work_day_hint(date).max(date.next_day())
+}
+
+/// Date before `date` from which to continue looking for work days;
+/// at most the previous day even if the [`WorkDays`] hint does not move backwards, so iterators always progress.
+fn previous_work_day_hint<Date: PlainDate, W: WorkDays<Date>>(work_days: &W, date: &Date) -> Date {
+    work_days.previous_work_day_hint(date).min(date.previous_day())
+}
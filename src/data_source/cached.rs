@@ -4,6 +4,12 @@ use crate::{Error, PlainDate};
 use crate::data_source::{DataSource, LoadDataSource};
 
 /// Built-in list of bank holidays used as a backup, in testing or when network requests are not available.
+///
+/// With the `historical` feature, this also includes bank holidays from 1978 until those published by GOV.UK.
+/// These were generated from the rules in force in each year using [`Computed`](crate::data_source::Computed)
+/// together with [`Special`](crate::data_source::Special) bank holidays, so are not official:
+/// they are marked as [`Provenance::Predicted`](crate::Provenance::Predicted)
+/// or [`Provenance::Manual`](crate::Provenance::Manual) respectively.
 pub struct Cached<Date: PlainDate>(PhantomData<fn() -> Date>);
 
 impl<Date: PlainDate> Default for Cached<Date> {
@@ -18,8 +24,17 @@ impl<Date: PlainDate> Cached<Date> {
     /// Does not need asynchronous loading.
     pub fn cached_data_source(&self) -> DataSource<Date> {
         const CACHED_DATA: &[u8] = include_bytes!("bank-holidays.json");
-        DataSource::try_from_json(CACHED_DATA)
-            .expect("cached data should be valid")
+        let data_source = DataSource::try_from_json(CACHED_DATA)
+            .expect("cached data should be valid");
+        #[cfg(feature = "historical")]
+        let data_source = {
+            const HISTORICAL_DATA: &[u8] = include_bytes!("bank-holidays-historical.json");
+            let mut historical_data_source = DataSource::try_from_json(HISTORICAL_DATA)
+                .expect("historical data should be valid");
+            historical_data_source.merge(data_source);
+            historical_data_source
+        };
+        data_source
     }
 }
 
@@ -29,3 +44,115 @@ impl<Date: PlainDate> LoadDataSource<Date> for Cached<Date> {
         Ok(self.cached_data_source())
     }
 }
+
+#[cfg(all(test, feature = "historical"))]
+mod tests {
+    use super::*;
+    use crate::{BankHolidayCalendar, Division, Provenance};
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn check_historical<Date: PlainDate>() {
+        let calendar = BankHolidayCalendar::<Date, _>::cached();
+        for division in [None, Some(Division::EnglandAndWales), Some(Division::Scotland), Some(Division::NorthernIreland)] {
+            assert_eq!(calendar.coverage(division), Some(1978..=2028), "Unexpected coverage in {division:?}");
+        }
+
+        // known dates checked by hand against the Banking and Financial Dealings Act 1971,
+        // royal proclamations and Easter tables, not generated by `Computed`
+        let expectation = [
+            // New Year’s Day and Boxing Day were added by proclamation in 1974
+            ((1978, 1, 2), None, true),
+            ((1978, 12, 26), None, true),
+            // Easter 1978 was 26th March; Easter Monday was not a bank holiday in Scotland
+            ((1978, 3, 24), None, true),
+            ((1978, 3, 27), Some(Division::EnglandAndWales), true),
+            ((1978, 3, 27), Some(Division::Scotland), false),
+            // Early May bank holiday started outside Scotland in 1978
+            ((1978, 5, 1), Some(Division::EnglandAndWales), true),
+            ((1978, 5, 1), Some(Division::Scotland), true),
+            // wedding of the Prince of Wales
+            ((1981, 7, 29), None, true),
+            // Easter 1984 was 22nd April
+            ((1984, 4, 20), None, true),
+            ((1984, 4, 23), Some(Division::NorthernIreland), true),
+            // Summer bank holidays differ in Scotland
+            ((1985, 8, 5), Some(Division::Scotland), true),
+            ((1985, 8, 5), Some(Division::EnglandAndWales), false),
+            ((1985, 8, 26), Some(Division::EnglandAndWales), true),
+            ((1989, 5, 29), None, true),
+            ((1990, 7, 12), Some(Division::NorthernIreland), true),
+            ((1990, 7, 12), Some(Division::EnglandAndWales), false),
+            // VE day anniversary moved the Early May bank holiday
+            ((1995, 5, 1), Some(Division::EnglandAndWales), false),
+            ((1995, 5, 8), None, true),
+            // 12th July 1998 was a Sunday
+            ((1998, 7, 13), Some(Division::NorthernIreland), true),
+            // millennium celebrations, with Christmas and New Year falling on weekends
+            ((1999, 12, 27), None, true),
+            ((1999, 12, 28), None, true),
+            ((1999, 12, 31), None, true),
+            ((2000, 1, 3), None, true),
+            ((2000, 1, 4), Some(Division::Scotland), true),
+            ((2000, 1, 4), Some(Division::EnglandAndWales), false),
+            // Golden Jubilee, with the Spring bank holiday moved to the day after
+            ((2002, 5, 27), Some(Division::EnglandAndWales), false),
+            ((2002, 6, 3), None, true),
+            ((2002, 6, 4), None, true),
+            ((2004, 12, 27), None, true),
+            ((2004, 12, 28), None, true),
+            // St Andrew’s Day became a bank holiday in 2007
+            ((2006, 11, 30), Some(Division::Scotland), false),
+            ((2007, 11, 30), Some(Division::Scotland), true),
+            ((2009, 7, 13), Some(Division::NorthernIreland), true),
+            ((2010, 12, 27), None, true),
+            ((2010, 12, 28), None, true),
+            // Easter 2011 was 24th April, followed by the wedding of Prince William
+            ((2011, 4, 22), None, true),
+            ((2011, 4, 25), Some(Division::EnglandAndWales), true),
+            ((2011, 4, 29), None, true),
+            ((2011, 5, 2), None, true),
+        ];
+        for ((year, month, day), division, expected) in expectation {
+            let date = Date::try_from_components(year, month, day).unwrap();
+            assert_eq!(
+                calendar.is_holiday(&date, division), expected,
+                "Expect {date:?} to be a bank holiday in {division:?}: {expected}",
+            );
+        }
+
+        // historical bank holidays are not official
+        let historical = DataSource::<Date>::try_from_json(include_bytes!("bank-holidays-historical.json"))
+            .expect("historical data should be valid")
+            .into_inner();
+        for division in Division::all() {
+            for bank_holiday in &historical[&division] {
+                let expected_provenance = if bank_holiday.is_special() { Provenance::Manual } else { Provenance::Predicted };
+                assert_eq!(bank_holiday.provenance(), expected_provenance, "Unexpected provenance of {bank_holiday:?}");
+            }
+        }
+        let millennium = Date::try_from_components(1999, 12, 31).unwrap();
+        let millennium = calendar.lookup(&millennium, None);
+        assert!(matches!(
+            millennium,
+            crate::HolidayLookup::Holiday(bank_holiday)
+                if bank_holiday.title() == "Millennium celebrations" && bank_holiday.provenance() == Provenance::Manual
+        ));
+        let official_year = Date::try_from_components(2012, 1, 2).unwrap();
+        assert!(matches!(
+            calendar.lookup(&official_year, None),
+            crate::HolidayLookup::Holiday(bank_holiday) if bank_holiday.provenance().is_official()
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        check_historical::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_historical::<crate::dates::time::DateImpl>();
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+use crate::{BankHoliday, Division, Error, PlainDate, Provenance, Weekday};
+use crate::data_source::{DataSource, LoadDataSource};
+
+/// Bank holidays generated from the rules set out in legislation and regular royal proclamations.
+/// Useful for predicting years beyond those published by GOV.UK.
+///
+/// Rules follow those in force in each year since the Banking and Financial Dealings Act 1971;
+/// earlier years are generated as if it applied.
+///
+/// NB: One-off bank holidays, such as for royal jubilees, cannot be predicted
+/// and nor can regular bank holidays that are moved by royal proclamation.
+/// See [`Special`](crate::data_source::Special) to apply these.
+pub struct Computed<Date: PlainDate> {
+    years: RangeInclusive<i32>,
+    _phantom: PhantomData<fn() -> Date>,
+}
+
+impl<Date: PlainDate> Computed<Date> {
+    /// Create a generator of bank holidays for the given range of years.
+    #[inline]
+    pub const fn new(years: RangeInclusive<i32>) -> Self {
+        Self { years, _phantom: PhantomData }
+    }
+
+    /// Create [`DataSource`] from generated bank holidays.
+    /// Does not need asynchronous loading, but fails if any date cannot be represented.
+    pub fn computed_data_source(&self) -> Result<DataSource<Date>, Error> {
+        let mut holiday_map = HashMap::with_capacity(3);
+        for division in Division::all() {
+            let mut bank_holidays = Vec::new();
+            for year in self.years.clone() {
+                bank_holidays.extend(Self::holidays_in_year(year, division)?);
+            }
+            holiday_map.insert(division, bank_holidays);
+        }
+        let mut data_source = DataSource::new(holiday_map);
+        data_source.sort();
+        Ok(data_source)
+    }
+
+    /// Generate bank holidays in given `year` and `division`, in no particular order.
+    fn holidays_in_year(year: i32, division: Division) -> Result<Vec<BankHoliday<Date>>, Error> {
+        let date = |month: u8, day: u8| Date::try_from_components(year, month, day);
+        let holiday = |date: Date, title: &str, bunting: bool| {
+            BankHoliday::new_with_notes(date, title.to_owned(), "".to_owned())
+                .with_bunting(bunting)
+                .with_provenance(Provenance::Predicted)
+        };
+
+        // holidays that always fall on a particular day of the week
+        let (easter_month, easter_day) = easter_sunday(year);
+        let easter_sunday = date(easter_month, easter_day)?;
+        let mut holidays = vec![
+            holiday(easter_sunday.previous_day().previous_day(), "Good Friday", false),
+            holiday(last_monday(date(5, 31)?), "Spring bank holiday", true),
+        ];
+        // Scotland always had a bank holiday on the first Monday in May, the rest of the UK only from 1978
+        if division == Division::Scotland || year >= 1978 {
+            holidays.push(holiday(first_monday(date(5, 1)?), "Early May bank holiday", true));
+        }
+        if division == Division::Scotland {
+            holidays.push(holiday(first_monday(date(8, 1)?), "Summer bank holiday", true));
+        } else {
+            holidays.push(holiday(easter_sunday.next_day(), "Easter Monday", true));
+            holidays.push(holiday(last_monday(date(8, 31)?), "Summer bank holiday", true));
+        }
+
+        // holidays on fixed dates which are substituted by a following work day if they fall on a weekend;
+        // groups of consecutive holidays are substituted together
+        let mut new_year = Vec::with_capacity(2);
+        let mut christmas = vec![holiday(date(12, 25)?, "Christmas Day", true)];
+        let mut fixed_holidays = Vec::with_capacity(2);
+        match division {
+            Division::EnglandAndWales => {
+                if year >= 1974 {
+                    new_year.push(holiday(date(1, 1)?, "New Year’s Day", true));
+                }
+                christmas.push(holiday(date(12, 26)?, "Boxing Day", true));
+            }
+            Division::Scotland => {
+                new_year.push(holiday(date(1, 1)?, "New Year’s Day", true));
+                new_year.push(holiday(date(1, 2)?, "2nd January", true));
+                if year >= 1974 {
+                    christmas.push(holiday(date(12, 26)?, "Boxing Day", true));
+                }
+                if year >= 2007 {
+                    fixed_holidays.push(vec![holiday(date(11, 30)?, "St Andrew’s Day", true)]);
+                }
+            }
+            Division::NorthernIreland => {
+                if year >= 1974 {
+                    new_year.push(holiday(date(1, 1)?, "New Year’s Day", true));
+                }
+                christmas.push(holiday(date(12, 26)?, "Boxing Day", true));
+                fixed_holidays.push(vec![holiday(date(3, 17)?, "St Patrick’s Day", true)]);
+                fixed_holidays.push(vec![holiday(date(7, 12)?, "Battle of the Boyne (Orangemen’s Day)", false)]);
+            }
+        }
+        fixed_holidays.push(new_year);
+        fixed_holidays.push(christmas);
+        for group in fixed_holidays {
+            let (on_weekdays, on_weekends): (Vec<_>, Vec<_>) = group.into_iter()
+                .partition(|holiday| !is_weekend(holiday.date()));
+            holidays.extend(on_weekdays);
+            for holiday in on_weekends {
+                let mut substitute_date = holiday.date().next_day();
+                while is_weekend(&substitute_date) || holidays.iter().any(|holiday| holiday.date() == &substitute_date) {
+                    substitute_date = substitute_date.next_day();
+                }
+                let substitute = BankHoliday::new_with_notes(
+                    substitute_date,
+                    holiday.title().to_owned(),
+                    "Substitute day".to_owned(),
+                )
+                    .with_bunting(holiday.bunting())
+                    .with_provenance(Provenance::Predicted);
+                holidays.push(substitute);
+            }
+        }
+
+        Ok(holidays)
+    }
+}
+
+impl<Date: PlainDate> LoadDataSource<Date> for Computed<Date> {
+    #[inline]
+    async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
+        self.computed_data_source()
+    }
+}
+
+/// Month and day of Easter Sunday in the Gregorian calendar using the “anonymous” computus.
+fn easter_sunday(year: i32) -> (u8, u8) {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    (month as u8, day as u8)
+}
+
+#[inline]
+fn is_weekend<Date: PlainDate>(date: &Date) -> bool {
+    matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+}
+
+/// First Monday on or after `date`.
+fn first_monday<Date: PlainDate>(mut date: Date) -> Date {
+    while date.weekday() != Weekday::Monday {
+        date = date.next_day();
+    }
+    date
+}
+
+/// Last Monday on or before `date`.
+fn last_monday<Date: PlainDate>(mut date: Date) -> Date {
+    while date.weekday() != Weekday::Monday {
+        date = date.previous_day();
+    }
+    date
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easter() {
+        for (year, expected) in [
+            (1818, (3, 22)),
+            (1943, (4, 25)),
+            (2000, (4, 23)),
+            (2008, (3, 23)),
+            (2019, (4, 21)),
+            (2024, (3, 31)),
+            (2025, (4, 20)),
+            (2038, (4, 25)),
+            (2285, (3, 22)),
+        ] {
+            assert_eq!(easter_sunday(year), expected, "Unexpected Easter Sunday in {year}");
+        }
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn check_computed<Date: PlainDate>() {
+        use crate::data_source::Cached;
+
+        // one-off bank holidays and the regular ones they moved cannot be computed
+        const SPECIAL_DATES: [(i32, u8, u8); 7] = [
+            (2012, 6, 4), (2012, 6, 5), (2020, 5, 8), (2022, 6, 2), (2022, 6, 3), (2022, 9, 19), (2023, 5, 8),
+        ];
+        const MOVED_DATES: [(i32, u8, u8); 3] = [(2012, 5, 28), (2020, 5, 4), (2022, 5, 30)];
+
+        let computed = Computed::<Date>::new(2012..=2028).computed_data_source()
+            .expect("bank holidays should be computed")
+            .into_inner();
+        let cached = Cached::<Date>::default().cached_data_source().into_inner();
+        for division in Division::all() {
+            let computed: Vec<_> = computed[&division].iter()
+                .filter(|holiday| !MOVED_DATES.contains(&holiday.date().as_components()))
+                .collect();
+            let cached: Vec<_> = cached[&division].iter()
+                .filter(|holiday| holiday.date().year() >= 2012 && !SPECIAL_DATES.contains(&holiday.date().as_components()))
+                .collect();
+            assert_eq!(computed.len(), cached.len(), "Unexpected number of bank holidays in {division:?}");
+            for (computed, cached) in computed.into_iter().zip(cached) {
+                assert_eq!(computed.provenance(), Provenance::Predicted);
+                if division == Division::Scotland && matches!(cached.date().as_components(), (2023, 1, 2) | (2023, 1, 3)) {
+                    // NB: GOV.UK labelled these the other way round to other years when 1st January was a Sunday
+                    assert_eq!(computed.date(), cached.date());
+                    continue;
+                }
+                // NB: GOV.UK did not put up bunting for Easter Monday in 2020
+                let bunting = if cached.date().as_components() == (2020, 4, 13) { false } else { computed.bunting() };
+                // NB: compares notes and bunting too
+                let computed = computed.clone()
+                    .with_bunting(bunting)
+                    .with_provenance(Provenance::Official);
+                assert_eq!(&computed, cached, "Unexpected bank holiday in {division:?}");
+            }
+        }
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn check_historical_rules<Date: PlainDate>() {
+        let computed = Computed::<Date>::new(1971..=2011).computed_data_source()
+            .expect("bank holidays should be computed")
+            .into_inner();
+        let titles_in_year = |division: Division, year: i32| {
+            computed[&division].iter()
+                .filter(|holiday| holiday.date().year() == year)
+                .map(|holiday| holiday.title())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            titles_in_year(Division::EnglandAndWales, 1973),
+            ["Good Friday", "Easter Monday", "Spring bank holiday", "Summer bank holiday", "Christmas Day", "Boxing Day"],
+        );
+        assert_eq!(
+            titles_in_year(Division::EnglandAndWales, 1978),
+            [
+                "New Year’s Day", "Good Friday", "Easter Monday", "Early May bank holiday", "Spring bank holiday",
+                "Summer bank holiday", "Christmas Day", "Boxing Day",
+            ],
+        );
+        assert_eq!(
+            titles_in_year(Division::Scotland, 1973),
+            [
+                "New Year’s Day", "2nd January", "Good Friday", "Early May bank holiday", "Spring bank holiday",
+                "Summer bank holiday", "Christmas Day",
+            ],
+        );
+        assert!(!titles_in_year(Division::Scotland, 2006).contains(&"St Andrew’s Day"));
+        assert!(titles_in_year(Division::Scotland, 2007).contains(&"St Andrew’s Day"));
+        assert!(titles_in_year(Division::NorthernIreland, 1971).contains(&"Battle of the Boyne (Orangemen’s Day)"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        check_computed::<crate::dates::chrono::DateImpl>();
+        check_historical_rules::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_computed::<crate::dates::time::DateImpl>();
+        check_historical_rules::<crate::dates::time::DateImpl>();
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use serde::de::{value::{Error as ValueError, StrDeserializer}, Deserialize};
+
+use crate::{BankHoliday, Division, Error, PlainDate, Provenance};
+use crate::data_source::DataSource;
+use crate::dates::DateWrapper;
+
+/// Column headings in order; the last is optional and only written if any bank holiday is not official.
+const COLUMNS: [&str; 6] = ["division", "date", "title", "notes", "bunting", "provenance"];
+
+impl<Date: PlainDate> DataSource<Date> {
+    /// Parse CSV with a heading row and columns `division,date,title,notes,bunting`
+    /// and optionally `provenance`, which is otherwise assumed to be official.
+    /// Divisions are named as in GOV.UK data, eg. `england-and-wales`, and dates are in ISO 8601 format.
+    ///
+    /// NB: Call [`DataSource::sort`] if rows might not be in date order.
+    pub fn try_from_csv<T: AsRef<str>>(csv: T) -> Result<Self, Error> {
+        let csv = csv.as_ref();
+        let csv = csv.strip_prefix('\u{feff}').unwrap_or(csv);
+        let mut records = parse_records(csv)?.into_iter();
+        let Some((_, headings)) = records.next() else {
+            return Err(Error::Csv { row: 1, column: 1, reason: "Missing heading row" });
+        };
+        if headings.len() < COLUMNS.len() - 1 {
+            return Err(Error::Csv { row: 1, column: headings.len() + 1, reason: "Missing column" });
+        }
+        for (index, heading) in headings.iter().enumerate() {
+            let is_expected = COLUMNS.get(index)
+                .is_some_and(|expected| heading.trim().eq_ignore_ascii_case(expected));
+            if !is_expected {
+                return Err(Error::Csv { row: 1, column: index + 1, reason: "Unexpected column heading" });
+            }
+        }
+
+        let mut holiday_map: HashMap<Division, Vec<BankHoliday<Date>>> = HashMap::with_capacity(3);
+        for (row, fields) in records {
+            let error = |column: usize, reason| Error::Csv { row, column: column + 1, reason };
+            if fields.len() != headings.len() {
+                let column = fields.len().min(headings.len());
+                let reason = if fields.len() < headings.len() { "Missing column" } else { "Unexpected column" };
+                return Err(error(column, reason));
+            }
+            let division = Division::deserialize(StrDeserializer::<ValueError>::new(&fields[0]))
+                .map_err(|_| error(0, "Unknown division"))?;
+            let date = DateWrapper::<Date>::deserialize(StrDeserializer::<ValueError>::new(&fields[1]))
+                .map_err(|_| error(1, "Invalid date"))?;
+            let bunting = parse_bool(&fields[4])
+                .ok_or(error(4, "Bunting must be true or false"))?;
+            let provenance = match fields.get(5).map(String::as_str) {
+                None | Some("") => Provenance::Official,
+                Some(provenance) => Provenance::deserialize(StrDeserializer::<ValueError>::new(provenance))
+                    .map_err(|_| error(5, "Unknown provenance"))?,
+            };
+            let bank_holiday = BankHoliday::new_with_notes(date.0, fields[2].clone(), fields[3].clone())
+                .with_bunting(bunting)
+                .with_provenance(provenance);
+            holiday_map.entry(division).or_default().push(bank_holiday);
+        }
+        Ok(DataSource::new(holiday_map))
+    }
+
+    /// Serialise into CSV with a heading row and columns `division,date,title,notes,bunting`,
+    /// adding a `provenance` column if any bank holiday is not official.
+    /// Divisions are written in a stable order; those without bank holidays are not represented.
+    pub fn to_csv(&self) -> String {
+        let with_provenance = self.holiday_map.values()
+            .flatten()
+            .any(|bank_holiday| !bank_holiday.provenance().is_official());
+        let column_count = if with_provenance { COLUMNS.len() } else { COLUMNS.len() - 1 };
+
+        let mut csv = String::new();
+        write_record(&mut csv, &COLUMNS[..column_count]);
+        for division in Division::all() {
+            let Some(bank_holidays) = self.holiday_map.get(&division) else {
+                continue;
+            };
+            for bank_holiday in bank_holidays {
+                let date = bank_holiday.date().iso_date_string();
+                let bunting = if bank_holiday.bunting() { "true" } else { "false" };
+                let provenance = match bank_holiday.provenance() {
+                    Provenance::Official => "official",
+                    Provenance::Predicted => "predicted",
+                    Provenance::Manual => "manual",
+                };
+                let fields = [division.slug(), &date, bank_holiday.title(), bank_holiday.notes(), bunting, provenance];
+                write_record(&mut csv, &fields[..column_count]);
+            }
+        }
+        csv
+    }
+}
+
+/// Parse boolean as written by spreadsheets.
+fn parse_bool(field: &str) -> Option<bool> {
+    let field = field.trim();
+    if field.eq_ignore_ascii_case("true") {
+        Some(true)
+    } else if field.eq_ignore_ascii_case("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Split CSV (RFC 4180) into records of fields, each with its row number starting from 1.
+/// Blank rows are skipped.
+fn parse_records(csv: &str) -> Result<Vec<(usize, Vec<String>)>, Error> {
+    let mut records = Vec::new();
+    let mut row = 1;
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = csv.chars().peekable();
+    loop {
+        let c = chars.next();
+        match c {
+            Some('"') if field.is_empty() => {
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => field.push(c),
+                        None => {
+                            return Err(Error::Csv { row, column: fields.len() + 1, reason: "Unterminated quoted field" });
+                        }
+                    }
+                }
+                if !matches!(chars.peek(), None | Some(',' | '\r' | '\n')) {
+                    return Err(Error::Csv { row, column: fields.len() + 1, reason: "Unexpected character after quoted field" });
+                }
+            }
+            Some(',') => fields.push(std::mem::take(&mut field)),
+            Some('\r') if chars.peek() == Some(&'\n') => {}
+            Some('\n') | None => {
+                fields.push(std::mem::take(&mut field));
+                let is_blank = fields.len() == 1 && fields[0].is_empty();
+                if is_blank {
+                    fields.clear();
+                } else {
+                    records.push((row, std::mem::take(&mut fields)));
+                }
+                if c.is_none() {
+                    break;
+                }
+                row += 1;
+            }
+            Some(c) => field.push(c),
+        }
+    }
+    Ok(records)
+}
+
+/// Append a CSV record, quoting fields only where necessary.
+fn write_record(csv: &mut String, fields: &[&str]) {
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            csv.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) || field.starts_with(' ') || field.ends_with(' ') {
+            csv.push('"');
+            csv.push_str(&field.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(field);
+        }
+    }
+    csv.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records() {
+        let records = parse_records("a,b\r\n\"c,\"\"d\"\"\",\"e\nf\"\n\n,\n").expect("CSV should be valid");
+        assert_eq!(records, [
+            (1, vec!["a".to_owned(), "b".to_owned()]),
+            (2, vec!["c,\"d\"".to_owned(), "e\nf".to_owned()]),
+            (4, vec!["".to_owned(), "".to_owned()]),
+        ]);
+
+        let mut csv = String::new();
+        write_record(&mut csv, &["plain", "with, comma", "with \"quotes\"", " padded", "line\nbreak"]);
+        assert_eq!(csv, "plain,\"with, comma\",\"with \"\"quotes\"\"\",\" padded\",\"line\nbreak\"\n");
+        let records = parse_records(&csv).expect("CSV should be valid");
+        assert_eq!(records[0].1, ["plain", "with, comma", "with \"quotes\"", " padded", "line\nbreak"]);
+
+        assert!(matches!(
+            parse_records("a,\"b\nc"),
+            Err(Error::Csv { row: 1, column: 2, reason: "Unterminated quoted field" }),
+        ));
+        assert!(matches!(
+            parse_records("a\nb,\"c\"d"),
+            Err(Error::Csv { row: 2, column: 2, reason: "Unexpected character after quoted field" }),
+        ));
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn check_csv<Date: PlainDate>() {
+        use crate::data_source::{Cached, Computed};
+
+        // embedded data round-trips through CSV
+        let cached = Cached::<Date>::default().cached_data_source();
+        let csv = cached.to_csv();
+        // NB: historical bank holidays are not official so need a provenance column
+        if cfg!(feature = "historical") {
+            assert!(csv.starts_with("division,date,title,notes,bunting,provenance\nengland-and-wales,"));
+            assert!(csv.contains("\nscotland,2022-12-27,Christmas Day,Substitute day,true,official\n"));
+        } else {
+            assert!(csv.starts_with("division,date,title,notes,bunting\nengland-and-wales,"));
+            assert!(csv.contains("\nscotland,2022-12-27,Christmas Day,Substitute day,true\n"));
+        }
+        let parsed = DataSource::<Date>::try_from_csv(&csv).expect("CSV should be valid");
+        assert_eq!(parsed.into_inner(), cached.into_inner());
+
+        // provenance is kept if not official
+        let computed = Computed::<Date>::new(2030..=2031).computed_data_source()
+            .expect("bank holidays should be computed");
+        let csv = computed.to_csv();
+        assert!(csv.starts_with("division,date,title,notes,bunting,provenance\n"));
+        assert!(csv.contains("\nnorthern-ireland,2030-07-12,Battle of the Boyne (Orangemen’s Day),,false,predicted\n"));
+        let parsed = DataSource::<Date>::try_from_csv(&csv).expect("CSV should be valid");
+        assert_eq!(parsed.into_inner(), computed.into_inner());
+
+        // spreadsheet output is accepted
+        let csv = "\u{feff}Division,Date,Title,Notes,Bunting,Provenance\r\n\
+            scotland,2024-08-05,Summer bank holiday,,TRUE,\r\n\
+            scotland,2024-12-24,Office closure,\"Christmas Eve, half day\",FALSE,manual\r\n";
+        let parsed = DataSource::<Date>::try_from_csv(csv).expect("CSV should be valid").into_inner();
+        assert_eq!(parsed.len(), 1);
+        let bank_holidays = &parsed[&Division::Scotland];
+        assert_eq!(bank_holidays.len(), 2);
+        assert!(bank_holidays[0].bunting());
+        assert_eq!(bank_holidays[0].provenance(), Provenance::Official);
+        assert_eq!(bank_holidays[1].notes(), "Christmas Eve, half day");
+        assert_eq!(bank_holidays[1].provenance(), Provenance::Manual);
+
+        // errors report row and column
+        for (csv, expected_row, expected_column, expected_reason) in [
+            ("", 1, 1, "Missing heading row"),
+            ("division,date,title\n", 1, 4, "Missing column"),
+            ("division,date,name,notes,bunting\n", 1, 3, "Unexpected column heading"),
+            ("division,date,title,notes,bunting,provenance,extra\n", 1, 7, "Unexpected column heading"),
+            ("division,date,title,notes,bunting\nwales,2024-01-01,New Year’s Day,,true\n", 2, 1, "Unknown division"),
+            ("division,date,title,notes,bunting\n\nscotland,2024-02-30,Leap day,,true\n", 3, 2, "Invalid date"),
+            ("division,date,title,notes,bunting\nscotland,2024-01-01,New Year’s Day,,yes\n", 2, 5, "Bunting must be true or false"),
+            ("division,date,title,notes,bunting\nscotland,2024-01-01,New Year’s Day\n", 2, 4, "Missing column"),
+            ("division,date,title,notes,bunting\nscotland,2024-01-01,New Year’s Day,,true,\n", 2, 6, "Unexpected column"),
+            ("division,date,title,notes,bunting,provenance\nscotland,2024-01-01,New Year’s Day,,true,guess\n", 2, 6, "Unknown provenance"),
+        ] {
+            let error = DataSource::<Date>::try_from_csv(csv)
+                .err()
+                .expect("invalid CSV should not be parsed");
+            assert!(
+                matches!(
+                    error,
+                    Error::Csv { row, column, reason }
+                        if row == expected_row && column == expected_column && reason == expected_reason
+                ),
+                "Unexpected error {error:?} for {csv:?}",
+            );
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        check_csv::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_csv::<crate::dates::time::DateImpl>();
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+
+use crate::{BankHoliday, Division, PlainDate};
+use crate::data_source::DataSource;
+
+/// Differences between two sets of bank holidays, division by division, as found by [`DataSource::diff`].
+/// Bank holidays are matched by date and, where several share a date, by title.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DataSourceDiff<Date: PlainDate> {
+    divisions: HashMap<Division, DivisionDiff<Date>>,
+}
+
+/// Differences between two lists of bank holidays in one division; each list is in date order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DivisionDiff<Date: PlainDate> {
+    added: Vec<BankHoliday<Date>>,
+    removed: Vec<BankHoliday<Date>>,
+    changed: Vec<ChangedHoliday<Date>>,
+}
+
+/// A bank holiday whose title or notes changed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChangedHoliday<Date: PlainDate> {
+    before: BankHoliday<Date>,
+    after: BankHoliday<Date>,
+}
+
+impl<Date: PlainDate> DataSource<Date> {
+    /// Find bank holidays that were added, removed or had their title or notes changed in `newer`.
+    pub fn diff(&self, newer: &DataSource<Date>) -> DataSourceDiff<Date> {
+        DataSourceDiff::between(&self.holiday_map, &newer.holiday_map)
+    }
+}
+
+impl<Date: PlainDate> Default for DataSourceDiff<Date> {
+    #[inline]
+    fn default() -> Self {
+        Self { divisions: HashMap::new() }
+    }
+}
+
+impl<Date: PlainDate> DataSourceDiff<Date> {
+    pub(crate) fn between(
+        before: &HashMap<Division, Vec<BankHoliday<Date>>>,
+        after: &HashMap<Division, Vec<BankHoliday<Date>>>,
+    ) -> Self {
+        let mut divisions = HashMap::new();
+        for division in Division::all() {
+            let before = before.get(&division).map(Vec::as_slice).unwrap_or_default();
+            let after = after.get(&division).map(Vec::as_slice).unwrap_or_default();
+            let division_diff = DivisionDiff::between(before, after);
+            if !division_diff.is_empty() {
+                divisions.insert(division, division_diff);
+            }
+        }
+        Self { divisions }
+    }
+
+    /// Whether there are no differences.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.divisions.is_empty()
+    }
+
+    /// Differences in given division, if there are any.
+    #[inline]
+    pub fn division(&self, division: Division) -> Option<&DivisionDiff<Date>> {
+        self.divisions.get(&division)
+    }
+
+    /// Iterator over divisions with differences, in a stable order.
+    pub fn iter(&self) -> impl Iterator<Item = (Division, &DivisionDiff<Date>)> {
+        Division::all()
+            .into_iter()
+            .filter_map(|division| self.divisions.get(&division).map(|division_diff| (division, division_diff)))
+    }
+}
+
+impl<Date: PlainDate> DivisionDiff<Date> {
+    fn between(before: &[BankHoliday<Date>], after: &[BankHoliday<Date>]) -> Self {
+        type OnDate<'a, Date> = (Vec<&'a BankHoliday<Date>>, Vec<&'a BankHoliday<Date>>);
+
+        let mut by_date: HashMap<&Date, OnDate<'_, Date>> = HashMap::new();
+        for bank_holiday in before {
+            by_date.entry(bank_holiday.date()).or_default().0.push(bank_holiday);
+        }
+        for bank_holiday in after {
+            by_date.entry(bank_holiday.date()).or_default().1.push(bank_holiday);
+        }
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        let mut changed = Vec::new();
+        for (before, mut after) in by_date.into_values() {
+            // NB: several bank holidays can share a date so those with the same title are matched first
+            let mut unmatched = Vec::new();
+            for before in before {
+                match after.iter().position(|after| after.title() == before.title()) {
+                    Some(index) => {
+                        let after = after.remove(index);
+                        if before.notes() != after.notes() {
+                            changed.push(ChangedHoliday { before: before.clone(), after: after.clone() });
+                        }
+                    }
+                    None => unmatched.push(before),
+                }
+            }
+            let mut after = after.into_iter();
+            for before in unmatched {
+                match after.next() {
+                    Some(after) => changed.push(ChangedHoliday { before: before.clone(), after: after.clone() }),
+                    None => removed.push(before.clone()),
+                }
+            }
+            added.extend(after.cloned());
+        }
+        added.sort();
+        removed.sort();
+        changed.sort_by(|a, b| a.before.cmp(&b.before));
+        Self { added, removed, changed }
+    }
+
+    /// Whether there are no differences.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Bank holidays that were not previously present.
+    #[inline]
+    pub fn added(&self) -> &[BankHoliday<Date>] {
+        &self.added
+    }
+
+    /// Bank holidays that are no longer present.
+    #[inline]
+    pub fn removed(&self) -> &[BankHoliday<Date>] {
+        &self.removed
+    }
+
+    /// Bank holidays whose title or notes changed.
+    #[inline]
+    pub fn changed(&self) -> &[ChangedHoliday<Date>] {
+        &self.changed
+    }
+}
+
+impl<Date: PlainDate> ChangedHoliday<Date> {
+    /// Bank holiday before the change.
+    #[inline]
+    pub fn before(&self) -> &BankHoliday<Date> {
+        &self.before
+    }
+
+    /// Bank holiday after the change.
+    #[inline]
+    pub fn after(&self) -> &BankHoliday<Date> {
+        &self.after
+    }
+}
+
+#[cfg(all(test, any(feature = "chrono", feature = "time")))]
+mod tests {
+    use super::*;
+    use crate::data_source::{Cached, Computed, Special};
+
+    fn check_diff<Date: PlainDate>() {
+        let cached = Cached::<Date>::default().cached_data_source();
+        assert!(cached.diff(&Cached::default().cached_data_source()).is_empty());
+
+        // language=json
+        let older = r#"{
+          "scotland": {
+            "division": "scotland",
+            "events": [
+              {"date": "2022-11-30", "title": "St Andrew’s Day", "notes": "", "bunting": true},
+              {"date": "2022-12-26", "title": "Boxing Day", "notes": "", "bunting": true},
+              {"date": "2022-12-27", "title": "Christmas Day", "notes": "Substitute day", "bunting": true}
+            ]
+          },
+          "northern-ireland": {
+            "division": "northern-ireland",
+            "events": [
+              {"date": "2022-03-17", "title": "St Patrick’s Day", "notes": "", "bunting": true}
+            ]
+          }
+        }"#;
+        // language=json
+        let newer = r#"{
+          "scotland": {
+            "division": "scotland",
+            "events": [
+              {"date": "2022-12-27", "title": "Christmas Day", "notes": "Substitute day", "bunting": false},
+              {"date": "2022-11-30", "title": "St Andrew’s Day", "notes": "Updated", "bunting": true},
+              {"date": "2022-09-19", "title": "State Funeral", "notes": "", "bunting": false}
+            ]
+          },
+          "northern-ireland": {
+            "division": "northern-ireland",
+            "events": [
+              {"date": "2022-03-17", "title": "St Patrick’s Day", "notes": "", "bunting": true}
+            ]
+          },
+          "england-and-wales": {
+            "division": "england-and-wales",
+            "events": []
+          }
+        }"#;
+        let older = DataSource::<Date>::try_from_json(older).expect("data source should be valid");
+        let newer = DataSource::<Date>::try_from_json(newer).expect("data source should be valid");
+        let diff = older.diff(&newer);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.iter().map(|(division, _)| division).collect::<Vec<_>>(), [Division::Scotland]);
+        assert!(diff.division(Division::NorthernIreland).is_none());
+        let scotland = diff.division(Division::Scotland).expect("Scotland should have differences");
+        assert_eq!(scotland.added().len(), 1);
+        assert_eq!(scotland.added()[0].title(), "State Funeral");
+        assert_eq!(scotland.removed().len(), 1);
+        assert_eq!(scotland.removed()[0].title(), "Boxing Day");
+        assert_eq!(scotland.changed().len(), 1);
+        assert_eq!(scotland.changed()[0].before().notes(), "");
+        assert_eq!(scotland.changed()[0].after().notes(), "Updated");
+
+        let reversed = newer.diff(&older);
+        let scotland = reversed.division(Division::Scotland).expect("Scotland should have differences");
+        assert_eq!(scotland.added()[0].title(), "Boxing Day");
+        assert_eq!(scotland.removed()[0].title(), "State Funeral");
+
+        // bank holidays sharing a date are compared individually
+        // language=json
+        let older = r#"{
+          "england-and-wales": {
+            "division": "england-and-wales",
+            "events": [
+              {"date": "2022-06-03", "title": "Platinum Jubilee bank holiday", "notes": "", "bunting": true},
+              {"date": "2022-06-03", "title": "Office closed", "notes": "", "bunting": false}
+            ]
+          }
+        }"#;
+        // language=json
+        let newer = r#"{
+          "england-and-wales": {
+            "division": "england-and-wales",
+            "events": [
+              {"date": "2022-06-03", "title": "Office closed", "notes": "All day", "bunting": false},
+              {"date": "2022-06-03", "title": "Platinum Jubilee bank holiday", "notes": "", "bunting": true},
+              {"date": "2022-06-03", "title": "Street party", "notes": "", "bunting": true}
+            ]
+          }
+        }"#;
+        let older = DataSource::<Date>::try_from_json(older).expect("data source should be valid");
+        let newer = DataSource::<Date>::try_from_json(newer).expect("data source should be valid");
+        let diff = older.diff(&newer);
+        let england_and_wales = diff.division(Division::EnglandAndWales).expect("England and Wales should have differences");
+        assert_eq!(england_and_wales.added().len(), 1);
+        assert_eq!(england_and_wales.added()[0].title(), "Street party");
+        assert!(england_and_wales.removed().is_empty());
+        assert_eq!(england_and_wales.changed().len(), 1);
+        assert_eq!(england_and_wales.changed()[0].before().title(), "Office closed");
+        assert_eq!(england_and_wales.changed()[0].after().notes(), "All day");
+        let reversed = newer.diff(&older);
+        let england_and_wales = reversed.division(Division::EnglandAndWales).expect("England and Wales should have differences");
+        assert!(england_and_wales.added().is_empty());
+        assert_eq!(england_and_wales.removed().len(), 1);
+        assert_eq!(england_and_wales.removed()[0].title(), "Street party");
+
+        // moved and one-off bank holidays show up as removed and added
+        let computed = Computed::<Date>::new(2022..=2022).computed_data_source()
+            .expect("bank holidays should be computed");
+        let mut with_special = Computed::<Date>::new(2022..=2022).computed_data_source()
+            .expect("bank holidays should be computed");
+        Special::default().apply(&mut with_special)
+            .expect("special bank holidays should apply");
+        let diff = computed.diff(&with_special);
+        assert_eq!(diff.iter().count(), 3);
+        for (division, division_diff) in diff.iter() {
+            assert_eq!(division_diff.added().len(), 3, "Unexpected additions in {division:?}");
+            assert_eq!(division_diff.removed().len(), 1, "Unexpected removals in {division:?}");
+            assert!(division_diff.changed().is_empty());
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        check_diff::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_diff::<crate::dates::time::DateImpl>();
+    }
+}
@@ -0,0 +1,278 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
+
+use crate::{Error, PlainDate};
+use crate::data_source::{DataSource, File, LoadDataSource};
+
+/// Wraps another source of bank holidays, saving the last successful result to a file
+/// and serving that file if the other source later fails.
+/// Useful so that a recent copy of GOV.UK data is used rather than the [`Cached`](crate::data_source::Cached) list
+/// if GOV.UK cannot be reached when an application starts.
+///
+/// The file is written atomically in the JSON format used by GOV.UK.
+/// With a maximum age, a file younger than that is served without trying the other source first.
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use govuk_bank_holidays::data_source::{DiskCache, LoadDataSource, Reqwest};
+///
+/// # #[cfg(feature = "chrono")]
+/// # async fn demo() {
+/// let loader = DiskCache::new(Reqwest::<chrono::NaiveDate>::default(), "/var/cache/bank-holidays.json")
+///     .with_max_age(Duration::from_secs(24 * 60 * 60));
+/// let (data_source, age) = loader.load_with_age().await
+///     .expect("bank holidays should load from GOV.UK or disk");
+/// if let Some(age) = age {
+///     println!("Using bank holidays saved {} seconds ago", age.as_secs());
+/// }
+/// # }
+/// ```
+pub struct DiskCache<Inner> {
+    inner: Inner,
+    path: PathBuf,
+    max_age: Option<Duration>,
+}
+
+impl<Inner> DiskCache<Inner> {
+    /// Create a loader that saves results of `inner` to given `path`, trying `inner` first every time.
+    #[inline]
+    pub fn new(inner: Inner, path: impl Into<PathBuf>) -> Self {
+        Self { inner, path: path.into(), max_age: None }
+    }
+
+    /// Same loader that serves a saved file younger than `max_age` without trying the inner source first.
+    #[inline]
+    pub fn with_max_age(self, max_age: Duration) -> Self {
+        Self { max_age: Some(max_age), ..self }
+    }
+
+    /// Path of the saved file.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Age of the saved file, if there is one.
+    pub async fn age(&self) -> Option<Duration> {
+        let modified = tokio::fs::metadata(&self.path).await
+            .and_then(|metadata| metadata.modified())
+            .ok()?;
+        Some(SystemTime::now().duration_since(modified).unwrap_or_default())
+    }
+
+    /// Load bank holidays along with the age of the saved file if that was served,
+    /// or `None` if they were loaded from the inner source.
+    /// Fails with the inner source’s error if that fails and there is no saved file that can be read.
+    pub async fn load_with_age<Date: PlainDate>(&self) -> Result<(DataSource<Date>, Option<Duration>), Error>
+    where
+        Inner: LoadDataSource<Date>,
+    {
+        let age = self.age().await;
+        if let (Some(age), Some(max_age)) = (age, self.max_age) {
+            if age <= max_age {
+                match self.read().await {
+                    Ok(data_source) => return Ok((data_source, Some(age))),
+                    Err(error) => tracing::warn!("Failed to read saved bank holidays: {error}"),
+                }
+            }
+        }
+
+        let error = match self.inner.load_data_source().await {
+            Ok(data_source) => {
+                if let Err(error) = self.write(&data_source).await {
+                    tracing::warn!("Failed to save bank holidays to {}: {error}", self.path.display());
+                }
+                return Ok((data_source, None));
+            }
+            Err(error) => error,
+        };
+        tracing::warn!("Failed to load bank holidays from {}: {error}", self.inner.name());
+        let Some(age) = age else {
+            return Err(error);
+        };
+        match self.read().await {
+            Ok(data_source) => {
+                tracing::info!("Falling back to bank holidays saved {} seconds ago", age.as_secs());
+                Ok((data_source, Some(age)))
+            }
+            Err(read_error) => {
+                tracing::warn!("Failed to read saved bank holidays: {read_error}");
+                Err(error)
+            }
+        }
+    }
+
+    async fn read<Date: PlainDate>(&self) -> Result<DataSource<Date>, Error> {
+        File::new(&self.path).load_data_source().await
+    }
+
+    /// Write to a temporary file in the same directory and then replace the saved file.
+    /// The temporary file name is unique to this write so concurrent writers do not interfere.
+    async fn write<Date: PlainDate>(&self, data_source: &DataSource<Date>) -> Result<(), Error> {
+        static WRITE_COUNT: AtomicU64 = AtomicU64::new(0);
+
+        let json = serde_json::to_vec(data_source)?;
+        let mut temporary_file_name = self.path.file_name()
+            .ok_or(Error::Generic("Path is not a file"))?
+            .to_owned();
+        let write_count = WRITE_COUNT.fetch_add(1, Ordering::Relaxed);
+        temporary_file_name.push(format!(".{}.{write_count}.tmp", std::process::id()));
+        let temporary_path = self.path.with_file_name(temporary_file_name);
+        let result = async {
+            tokio::fs::write(&temporary_path, json).await?;
+            // NB: contents must reach the disk before renaming otherwise a crash could leave an empty saved file
+            tokio::fs::OpenOptions::new()
+                .write(true)
+                .open(&temporary_path).await?
+                .sync_all().await?;
+            tokio::fs::rename(&temporary_path, &self.path).await
+        }.await;
+        if let Err(error) = result {
+            let _ = tokio::fs::remove_file(&temporary_path).await;
+            return Err(error.into());
+        }
+        // NB: the renamed directory entry must also reach the disk otherwise a crash could lose the new file
+        #[cfg(unix)]
+        {
+            let directory = self.path.parent()
+                .filter(|directory| !directory.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            tokio::fs::File::open(directory).await?
+                .sync_all().await?;
+        }
+        Ok(())
+    }
+}
+
+impl<Date, Inner> LoadDataSource<Date> for DiskCache<Inner>
+where
+    Date: PlainDate,
+    Inner: LoadDataSource<Date>,
+{
+    #[inline]
+    async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
+        self.load_with_age().await
+            .map(|(data_source, _age)| data_source)
+    }
+
+    fn name(&self) -> String {
+        format!("DiskCache {} of {}", self.path.display(), self.inner.name())
+    }
+
+    async fn load_named_data_source(&self) -> Result<(DataSource<Date>, String), Error> {
+        let (data_source, age) = self.load_with_age().await?;
+        let name = match age {
+            Some(_) => format!("DiskCache {}", self.path.display()),
+            None => self.inner.name(),
+        };
+        Ok((data_source, name))
+    }
+}
+
+#[cfg(all(test, any(feature = "chrono", feature = "time")))]
+mod tests {
+    use std::marker::PhantomData;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::Division;
+    use crate::data_source::Computed;
+
+    /// Counts loads and fails after the first few.
+    struct Flaky<Date: PlainDate> {
+        loads: AtomicUsize,
+        successes: usize,
+        _phantom: PhantomData<fn() -> Date>,
+    }
+
+    impl<Date: PlainDate> LoadDataSource<Date> for Flaky<Date> {
+        async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
+            let loads = self.loads.fetch_add(1, Ordering::SeqCst);
+            if loads < self.successes {
+                Computed::new(2030..=2030).load_data_source().await
+            } else {
+                Err(Error::Generic("unavailable"))
+            }
+        }
+    }
+
+    fn flaky<Date: PlainDate>(successes: usize) -> Flaky<Date> {
+        Flaky { loads: AtomicUsize::new(0), successes, _phantom: PhantomData }
+    }
+
+    async fn check_disk_cache<Date: PlainDate>(name: &str) {
+        let path = std::env::temp_dir().join(format!("govuk-bank-holidays-{}-{name}-cache.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        // without a saved file, failures are returned
+        let loader = DiskCache::new(flaky::<Date>(0), &path);
+        assert!(loader.age().await.is_none());
+        assert!(matches!(loader.load_with_age().await, Err(Error::Generic("unavailable"))));
+
+        // successful loads are saved and served if the inner source later fails
+        let loader = DiskCache::new(flaky::<Date>(1), &path);
+        let (data_source, age) = loader.load_with_age().await
+            .expect("bank holidays should load");
+        assert!(age.is_none());
+        assert_eq!(data_source.coverage(Division::Scotland), Some(2030..=2030));
+        assert!(loader.age().await.is_some());
+        let (data_source, name) = loader.load_named_data_source().await
+            .expect("saved bank holidays should load");
+        assert_eq!(name, format!("DiskCache {}", path.display()));
+        assert_eq!(data_source.coverage(Division::Scotland), Some(2030..=2030));
+        assert_eq!(loader.inner.loads.load(Ordering::SeqCst), 2);
+        let directory_entries = std::fs::read_dir(std::env::temp_dir())
+            .expect("temporary directory should be readable")
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_name().to_string_lossy().contains(&format!("{name}-cache.json.")))
+            .count();
+        assert_eq!(directory_entries, 0, "temporary file should be renamed");
+
+        // a young enough saved file is served without trying the inner source
+        let loader = DiskCache::new(flaky::<Date>(1), &path)
+            .with_max_age(Duration::from_secs(60 * 60));
+        let (_, age) = loader.load_with_age().await
+            .expect("saved bank holidays should load");
+        assert!(age.is_some());
+        assert_eq!(loader.inner.loads.load(Ordering::SeqCst), 0);
+
+        // an old saved file is refreshed first
+        let loader = DiskCache::new(flaky::<Date>(1), &path)
+            .with_max_age(Duration::ZERO);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let (_, age) = loader.load_with_age().await
+            .expect("bank holidays should load");
+        assert!(age.is_none());
+        assert_eq!(loader.inner.loads.load(Ordering::SeqCst), 1);
+
+        // concurrent writers use separate temporary files
+        let loaders = [DiskCache::new(flaky::<Date>(1), &path), DiskCache::new(flaky::<Date>(1), &path)];
+        let (first, second) = tokio::join!(loaders[0].load_with_age(), loaders[1].load_with_age());
+        assert!(first.is_ok() && second.is_ok());
+        let data_source = File::<Date>::new(&path).file_data_source()
+            .expect("saved bank holidays should load");
+        assert_eq!(data_source.coverage(Division::Scotland), Some(2030..=2030));
+        let directory_entries = std::fs::read_dir(std::env::temp_dir())
+            .expect("temporary directory should be readable")
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_name().to_string_lossy().contains(&format!("{name}-cache.json.")))
+            .count();
+        assert_eq!(directory_entries, 0, "temporary files should be renamed");
+
+        std::fs::remove_file(&path).expect("saved file should be removed");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn chrono() {
+        check_disk_cache::<crate::dates::chrono::DateImpl>("chrono").await;
+    }
+
+    #[cfg(feature = "time")]
+    #[tokio::test]
+    async fn time() {
+        check_disk_cache::<crate::dates::time::DateImpl>("time").await;
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Error, PlainDate};
+use crate::data_source::{DataSource, LoadDataSource};
+
+/// Loads bank holidays from the first source and, if that fails, from the second.
+/// Failures are logged and, if both sources fail, the error from the second is returned.
+///
+/// Usually created with [`LoadDataSource::or_else`] which can be chained to try any number of sources in order.
+/// Use [`LoadDataSource::load_named_data_source`] to find out which source succeeded.
+///
+/// ```no_run
+/// use govuk_bank_holidays::data_source::{Cached, File, LoadDataSource, Reqwest};
+///
+/// # #[cfg(feature = "chrono")]
+/// # async fn demo() {
+/// let loader = Reqwest::<chrono::NaiveDate>::new("https://mirror.example.com/bank-holidays.json")
+///     .or_else(Reqwest::default())
+///     .or_else(File::new("bank-holidays.json"))
+///     .or_else(Cached::default());
+/// let (data_source, name) = loader.load_named_data_source().await
+///     .expect("cached bank holidays are always available");
+/// println!("Loaded bank holidays from {name}");
+/// # }
+/// ```
+pub struct Fallback<First, Second> {
+    first: First,
+    second: Second,
+}
+
+impl<First, Second> Fallback<First, Second> {
+    /// Create a loader that tries `first` and then `second` if that fails.
+    #[inline]
+    pub const fn new(first: First, second: Second) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<Date, First, Second> LoadDataSource<Date> for Fallback<First, Second>
+where
+    Date: PlainDate,
+    First: LoadDataSource<Date>,
+    Second: LoadDataSource<Date>,
+{
+    #[inline]
+    async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
+        self.load_named_data_source().await
+            .map(|(data_source, _name)| data_source)
+    }
+
+    fn name(&self) -> String {
+        format!("{} or {}", self.first.name(), self.second.name())
+    }
+
+    async fn load_named_data_source(&self) -> Result<(DataSource<Date>, String), Error> {
+        match self.first.load_named_data_source().await {
+            Ok(loaded) => Ok(loaded),
+            Err(error) => {
+                tracing::warn!("Failed to load bank holidays from {}: {error}", self.first.name());
+                let loaded = self.second.load_named_data_source().await;
+                if let Err(error) = &loaded {
+                    tracing::warn!("Failed to load bank holidays from {}: {error}", self.second.name());
+                }
+                loaded
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    async fn check_fallback<Date: PlainDate>() {
+        use crate::Division;
+        use crate::data_source::{Cached, Computed};
+        use crate::data_source::testing::Failing;
+
+        assert_eq!(LoadDataSource::<Date>::name(&Cached::<Date>::default()), "Cached");
+        assert_eq!(LoadDataSource::<Date>::name(&Failing::<Date>::new("mirror")), "Failing");
+
+        // first source to succeed is used
+        let loader = Failing::<Date>::new("mirror")
+            .or_else(Failing::new("GOV.UK"))
+            .or_else(Computed::new(2030..=2030))
+            .or_else(Cached::default());
+        assert_eq!(loader.name(), "Failing or Failing or Computed or Cached");
+        let (data_source, name) = loader.load_named_data_source().await
+            .expect("bank holidays should be computed");
+        assert_eq!(name, "Computed");
+        assert_eq!(data_source.coverage(Division::Scotland), Some(2030..=2030));
+
+        let loader = Cached::<Date>::default()
+            .or_else(Failing::new("GOV.UK"));
+        let (data_source, name) = loader.load_named_data_source().await
+            .expect("cached bank holidays should load");
+        assert_eq!(name, "Cached");
+        assert!(data_source.coverage(Division::Scotland).is_some());
+
+        // the last error is returned if all fail
+        let loader = Fallback::new(Failing::<Date>::new("mirror"), Failing::new("GOV.UK"));
+        assert!(matches!(loader.load_data_source().await, Err(Error::Generic("GOV.UK"))));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn chrono() {
+        check_fallback::<crate::dates::chrono::DateImpl>().await;
+    }
+
+    #[cfg(feature = "time")]
+    #[tokio::test]
+    async fn time() {
+        check_fallback::<crate::dates::time::DateImpl>().await;
+    }
+}
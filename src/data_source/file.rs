@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+use crate::{Error, PlainDate};
+use crate::data_source::{DataSource, LoadDataSource};
+
+/// Loads bank holidays from a local file in the JSON format used by GOV.UK.
+/// Useful where network requests are not available but the [`Cached`](crate::data_source::Cached) list
+/// is not recent enough, such as shipping a vetted copy of the data alongside an application.
+///
+/// Like [`Reqwest`](crate::data_source::Reqwest), divisions are sorted by date and missing ones are added.
+pub struct File<Date: PlainDate> {
+    path: PathBuf,
+    _phantom: PhantomData<fn() -> Date>,
+}
+
+impl<Date: PlainDate> File<Date> {
+    /// Create a loader of bank holidays in JSON format from the given path.
+    #[inline]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), _phantom: PhantomData }
+    }
+
+    /// Create [`DataSource`] by reading the file, blocking the current thread.
+    pub fn file_data_source(&self) -> Result<DataSource<Date>, Error> {
+        tracing::debug!("Loading bank holidays from {}", self.path.display());
+        let json = std::fs::read(&self.path)?;
+        parse(json)
+    }
+}
+
+impl<Date: PlainDate> LoadDataSource<Date> for File<Date> {
+    /// Load [`DataSource`] by reading the file without blocking; requires a `tokio` runtime.
+    async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
+        tracing::debug!("Loading bank holidays from {}", self.path.display());
+        let json = tokio::fs::read(&self.path).await?;
+        parse(json)
+    }
+
+    #[inline]
+    fn name(&self) -> String {
+        format!("File {}", self.path.display())
+    }
+}
+
+fn parse<Date: PlainDate>(json: Vec<u8>) -> Result<DataSource<Date>, Error> {
+    let mut data_source = DataSource::try_from_json(json)?;
+    data_source.sort();
+    data_source.add_missing_divisions();
+    Ok(data_source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Path to a JSON file in the temporary directory, unique to this process.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn temporary_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("govuk-bank-holidays-{}-{name}.json", std::process::id()))
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    async fn check_file<Date: PlainDate>(name: &str) {
+        use crate::Division;
+
+        // language=json
+        let json = r#"{
+          "scotland": {
+            "division": "scotland",
+            "events": [
+              {
+                "date": "2022-12-26",
+                "title": "Boxing Day",
+                "notes": "",
+                "bunting": true
+              },
+              {
+                "date": "2022-11-30",
+                "title": "St Andrew’s Day",
+                "notes": "",
+                "bunting": true
+              }
+            ]
+          }
+        }"#;
+        let path = temporary_path(name);
+        std::fs::write(&path, json).expect("temporary file should be written");
+
+        let loader = File::<Date>::new(&path);
+        let blocking = loader.file_data_source()
+            .expect("file should be loaded")
+            .into_inner();
+        let non_blocking = loader.load_data_source().await
+            .expect("file should be loaded")
+            .into_inner();
+        std::fs::remove_file(&path).expect("temporary file should be removed");
+        for data_source in [blocking, non_blocking] {
+            assert!(Division::all().iter().all(|division| data_source.contains_key(division)));
+            let bank_holidays = &data_source[&Division::Scotland];
+            assert_eq!(bank_holidays.len(), 2);
+            assert_eq!(bank_holidays[0].title(), "St Andrew’s Day");
+        }
+
+        let loader = File::<Date>::new(&path);
+        assert!(matches!(loader.file_data_source(), Err(Error::Io(_))));
+        assert!(matches!(loader.load_data_source().await, Err(Error::Io(_))));
+
+        std::fs::write(&path, "[]").expect("temporary file should be written");
+        let result = loader.file_data_source();
+        std::fs::remove_file(&path).expect("temporary file should be removed");
+        assert!(matches!(result, Err(Error::Parsing(_))));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn chrono() {
+        check_file::<crate::dates::chrono::DateImpl>("chrono").await;
+    }
+
+    #[cfg(feature = "time")]
+    #[tokio::test]
+    async fn time() {
+        check_file::<crate::dates::time::DateImpl>("time").await;
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{BankHoliday, BankHolidayCalendar, Division, PlainDate, WorkDays};
+use crate::data_source::DataSource;
+use crate::dates::components_from_day_number;
+
+impl<Date: PlainDate> DataSource<Date> {
+    /// Serialise bank holidays in given `division` into iCalendar format (RFC 5545),
+    /// with each bank holiday as an all-day event.
+    pub fn to_icalendar(&self, division: Division) -> String {
+        let bank_holidays = self.holiday_map.get(&division)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        write_icalendar(bank_holidays, Some(division))
+    }
+}
+
+impl<Date: PlainDate, W: WorkDays<Date>> BankHolidayCalendar<Date, W> {
+    /// Serialise all known holidays in given `division` of the UK or only those common to all divisions
+    /// into iCalendar format (RFC 5545), with each bank holiday as an all-day event.
+    pub fn to_icalendar(&self, division: Option<Division>) -> String {
+        write_icalendar(self.holidays(division), division)
+    }
+}
+
+/// Write bank holidays as all-day events into an iCalendar.
+/// Event UIDs are derived from the date, division and title so are stable between exports.
+///
+/// NB: Bank holidays on the last date that `Date` can represent are skipped because their events cannot end.
+fn write_icalendar<'a, Date: PlainDate + 'a>(
+    bank_holidays: impl IntoIterator<Item = &'a BankHoliday<Date>>,
+    division: Option<Division>,
+) -> String {
+    let (name, slug) = match division {
+        Some(division) => (division.name(), division.slug()),
+        None => ("the United Kingdom", "united-kingdom"),
+    };
+    let timestamp = utc_timestamp(SystemTime::now());
+
+    let mut icalendar = String::new();
+    let mut write_line = |line: &str| {
+        fold_line(&mut icalendar, line);
+    };
+    write_line("BEGIN:VCALENDAR");
+    write_line("VERSION:2.0");
+    write_line("PRODID:-//govuk-bank-holidays//Bank holidays//EN");
+    write_line("CALSCALE:GREGORIAN");
+    write_line(&format!("X-WR-CALNAME:{}", escape_text(&format!("Bank holidays in {name}"))));
+    let mut uids = HashSet::new();
+    for bank_holiday in bank_holidays {
+        let date = bank_holiday.date();
+        let Ok(end_date) = date.try_add_days(1) else {
+            continue;
+        };
+        let base_uid = format!("{}-{slug}-{}", date.iso_date_string(), title_slug(bank_holiday.title()));
+        let mut uid = base_uid.clone();
+        let mut index = 1;
+        while !uids.insert(uid.clone()) {
+            index += 1;
+            uid = format!("{base_uid}-{index}");
+        }
+        write_line("BEGIN:VEVENT");
+        write_line(&format!("UID:{uid}@govuk-bank-holidays"));
+        write_line(&format!("DTSTAMP:{timestamp}"));
+        write_line(&format!("DTSTART;VALUE=DATE:{}", ical_date(date)));
+        write_line(&format!("DTEND;VALUE=DATE:{}", ical_date(&end_date)));
+        write_line(&format!("SUMMARY:{}", escape_text(bank_holiday.title())));
+        if !bank_holiday.notes().is_empty() {
+            write_line(&format!("DESCRIPTION:{}", escape_text(bank_holiday.notes())));
+        }
+        write_line("TRANSP:TRANSPARENT");
+        write_line("END:VEVENT");
+    }
+    write_line("END:VCALENDAR");
+    icalendar
+}
+
+/// Lowercase ASCII letters and digits of a title, with other runs of characters replaced by hyphens.
+fn title_slug(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if matches!(c, '\'' | '’') {
+            continue;
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+/// Format date as `YYYYMMDD`.
+fn ical_date<Date: PlainDate>(date: &Date) -> String {
+    let (year, month, day) = date.as_components();
+    format!("{year:04}{month:02}{day:02}")
+}
+
+/// Format time as `YYYYMMDDTHHMMSSZ` in UTC.
+fn utc_timestamp(time: SystemTime) -> String {
+    let seconds = time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_default();
+    let (year, month, day) = components_from_day_number(seconds.div_euclid(86_400));
+    let seconds = seconds.rem_euclid(86_400);
+    format!(
+        "{year:04}{month:02}{day:02}T{:02}{:02}{:02}Z",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+    )
+}
+
+/// Escape special characters in a text property value.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Append a content line, folded so that no line is longer than 75 octets, terminated with CRLF.
+fn fold_line(output: &mut String, line: &str) {
+    const MAX_OCTETS: usize = 75;
+
+    let mut line_length = 0;
+    for c in line.chars() {
+        if line_length + c.len_utf8() > MAX_OCTETS {
+            output.push_str("\r\n ");
+            line_length = 1;
+        }
+        output.push(c);
+        line_length += c.len_utf8();
+    }
+    output.write_str("\r\n").expect("writing to string should not fail");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folding_and_escaping() {
+        let mut output = String::new();
+        fold_line(&mut output, "SUMMARY:short");
+        assert_eq!(output, "SUMMARY:short\r\n");
+
+        let mut output = String::new();
+        let line = format!("SUMMARY:{}", "’".repeat(40));
+        fold_line(&mut output, &line);
+        let lines: Vec<_> = output.split("\r\n").collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.len() <= 75));
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(lines[2], "");
+        assert_eq!(output.replace("\r\n ", ""), format!("{line}\r\n"));
+
+        let text = "Christmas, Boxing; Day\\\nBank holiday";
+        assert_eq!(escape_text(text), "Christmas\\, Boxing\\; Day\\\\\\nBank holiday");
+        assert_eq!(super::super::unescape_text(&escape_text(text)), text);
+
+        assert_eq!(title_slug("St Andrew’s Day"), "st-andrews-day");
+        assert_eq!(title_slug("Battle of the Boyne (Orangemen’s Day)"), "battle-of-the-boyne-orangemens-day");
+        assert_eq!(title_slug("Bank holiday"), "bank-holiday");
+        assert_eq!(title_slug("—"), "");
+
+        let time = UNIX_EPOCH + std::time::Duration::from_secs(1_709_164_805);
+        assert_eq!(utc_timestamp(time), "20240229T000005Z");
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn check_icalendar<Date: PlainDate>() {
+        use crate::data_source::Cached;
+
+        let data_source = Cached::<Date>::default().cached_data_source();
+        let icalendar = data_source.to_icalendar(Division::Scotland);
+        assert!(icalendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(icalendar.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(icalendar.contains("X-WR-CALNAME:Bank holidays in Scotland\r\n"));
+        assert!(icalendar.contains(concat!(
+            "BEGIN:VEVENT\r\n",
+            "UID:2022-11-30-scotland-st-andrews-day@govuk-bank-holidays\r\n",
+        )));
+        assert!(icalendar.contains(concat!(
+            "DTSTART;VALUE=DATE:20221227\r\n",
+            "DTEND;VALUE=DATE:20221228\r\n",
+            "SUMMARY:Christmas Day\r\n",
+            "DESCRIPTION:Substitute day\r\n",
+            "TRANSP:TRANSPARENT\r\n",
+            "END:VEVENT\r\n",
+        )));
+        let event_count = icalendar.matches("BEGIN:VEVENT").count();
+        let calendar = BankHolidayCalendar::<Date, _>::cached();
+        assert_eq!(event_count, calendar.holidays(Some(Division::Scotland)).len());
+
+        let icalendar = calendar.to_icalendar(None);
+        assert!(icalendar.contains("X-WR-CALNAME:Bank holidays in the United Kingdom\r\n"));
+        assert!(icalendar.contains("UID:2022-12-26-united-kingdom-boxing-day@govuk-bank-holidays\r\n"));
+        assert!(!icalendar.contains("SUMMARY:St Andrew’s Day\r\n"));
+        assert_eq!(icalendar.matches("BEGIN:VEVENT").count(), calendar.holidays(None).len());
+
+        // holidays sharing a date have distinct UIDs and those that cannot end are skipped
+        let date = Date::try_from_components(2024, 12, 25).unwrap();
+        let mut bank_holidays = vec![
+            BankHoliday::new(date.clone(), "Christmas Day".to_owned()),
+            BankHoliday::new(date.clone(), "Office closed".to_owned()),
+            BankHoliday::new_with_notes(date, "Office closed".to_owned(), "Again".to_owned()),
+        ];
+        let last_date = [(262_142, 12, 31), (9999, 12, 31)].into_iter()
+            .filter_map(|(year, month, day)| Date::try_from_components(year, month, day).ok())
+            .find(|date| date.try_add_days(1).is_err());
+        if let Some(last_date) = &last_date {
+            bank_holidays.push(BankHoliday::new(last_date.clone(), "End of time".to_owned()));
+        }
+        let data_source = DataSource::new([(Division::Scotland, bank_holidays)].into_iter().collect());
+        let icalendar = data_source.to_icalendar(Division::Scotland);
+        assert_eq!(icalendar.matches("BEGIN:VEVENT").count(), 3);
+        assert!(icalendar.contains("UID:2024-12-25-scotland-christmas-day@govuk-bank-holidays\r\n"));
+        assert!(icalendar.contains("UID:2024-12-25-scotland-office-closed@govuk-bank-holidays\r\n"));
+        assert!(icalendar.contains("UID:2024-12-25-scotland-office-closed-2@govuk-bank-holidays\r\n"));
+        assert!(!icalendar.contains("End of time"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        check_icalendar::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_icalendar::<crate::dates::time::DateImpl>();
+    }
+}
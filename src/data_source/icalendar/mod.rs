@@ -0,0 +1,430 @@
+use std::marker::PhantomData;
+
+use crate::{BankHoliday, Division, Error, PlainDate, Provenance};
+use crate::data_source::{DataSource, LoadDataSource};
+use crate::dates::day_number;
+
+#[cfg(feature = "icalendar")]
+mod export;
+
+/// Loads bank holidays, or other closure days, from all-day events in iCalendar format (RFC 5545).
+/// Every event is assigned to each of the given divisions and multi-day events become one bank holiday per day,
+/// up to a limit of 31 days. Events repeated exactly are only included once.
+/// The resulting [`DataSource`] can be combined with others using [`DataSource::merge`].
+///
+/// Bank holidays from iCalendar are marked as [`Provenance::Manual`] and have no bunting.
+///
+/// NB: Events that start at a time rather than on a date are not all-day so are skipped,
+/// as are recurrence rules.
+pub struct ICalendar<Date: PlainDate> {
+    icalendar: String,
+    divisions: Vec<Division>,
+    _phantom: PhantomData<fn() -> Date>,
+}
+
+impl<Date: PlainDate> ICalendar<Date> {
+    /// Create a loader of all-day events in iCalendar format, assigning them to the given divisions.
+    #[inline]
+    pub fn new(icalendar: impl Into<String>, divisions: impl Into<Vec<Division>>) -> Self {
+        Self { icalendar: icalendar.into(), divisions: divisions.into(), _phantom: PhantomData }
+    }
+
+    /// Create [`DataSource`] from iCalendar events.
+    /// Does not need asynchronous loading, but fails with [`Error::ICalendar`] if input is malformed.
+    pub fn icalendar_data_source(&self) -> Result<DataSource<Date>, Error> {
+        let mut bank_holidays = parse_icalendar(&self.icalendar)?;
+        bank_holidays.sort();
+        bank_holidays.dedup();
+        let holiday_map = self.divisions.iter()
+            .map(|division| (*division, bank_holidays.clone()))
+            .collect();
+        let mut data_source = DataSource::new(holiday_map);
+        data_source.add_missing_divisions();
+        Ok(data_source)
+    }
+}
+
+impl<Date: PlainDate> LoadDataSource<Date> for ICalendar<Date> {
+    #[inline]
+    async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
+        self.icalendar_data_source()
+    }
+}
+
+/// Longest event that is expanded into bank holidays, so that mistakes cannot create one for every day of a century.
+const MAX_EVENT_DAYS: i64 = 31;
+
+/// Value of a date property.
+enum DateValue<Date: PlainDate> {
+    Date(Date),
+    /// Date with time, so not an all-day event.
+    DateTime,
+}
+
+/// Properties of an event being parsed, with the line numbers where they were found.
+struct Event<Date: PlainDate> {
+    line: usize,
+    start: Option<DateValue<Date>>,
+    end: Option<(usize, DateValue<Date>)>,
+    duration: Option<(usize, String)>,
+    summary: String,
+    description: String,
+}
+
+impl<Date: PlainDate> Event<Date> {
+    fn new(line: usize) -> Self {
+        Self {
+            line,
+            start: None,
+            end: None,
+            duration: None,
+            summary: String::new(),
+            description: String::new(),
+        }
+    }
+
+    /// Expand an all-day event into a bank holiday for each day it spans.
+    fn into_bank_holidays(self) -> Result<Vec<BankHoliday<Date>>, Error> {
+        let error = |line, reason| Error::ICalendar { line, reason };
+        let start = match self.start {
+            Some(DateValue::Date(start)) => start,
+            Some(DateValue::DateTime) => return Ok(Vec::new()),
+            None => return Err(error(self.line, "Event has no start")),
+        };
+        let (line, days) = match (self.end, self.duration) {
+            (None, None) => (self.line, 1),
+            (Some((line, DateValue::Date(end))), None) => {
+                let days = day_number(&end) - day_number(&start);
+                if days < 1 {
+                    return Err(error(line, "Event ends before it starts"));
+                }
+                (line, days)
+            }
+            (Some((line, DateValue::DateTime)), None) => return Err(error(line, "Event end is not a date")),
+            (None, Some((line, duration))) => {
+                let days = parse_duration_days(&duration)
+                    .ok_or(error(line, "Unsupported event duration"))?;
+                (line, days)
+            }
+            (Some(_), Some((line, _))) => return Err(error(line, "Event has both end and duration")),
+        };
+        if days > MAX_EVENT_DAYS {
+            return Err(error(line, "Event is too long"));
+        }
+
+        (0..days)
+            .map(|offset| {
+                let date = start.try_add_days(offset)
+                    .map_err(|_| error(line, "Event date out of range"))?;
+                let bank_holiday = BankHoliday::new_with_notes(date, self.summary.clone(), self.description.clone())
+                    .with_provenance(Provenance::Manual);
+                Ok(bank_holiday)
+            })
+            .collect()
+    }
+}
+
+/// Parse all-day events into bank holidays, in no particular order.
+fn parse_icalendar<Date: PlainDate>(icalendar: &str) -> Result<Vec<BankHoliday<Date>>, Error> {
+    let mut bank_holidays = Vec::new();
+    let mut event: Option<Event<Date>> = None;
+    // depth of components, such as alarms, nested inside the current event
+    let mut nesting = 0_usize;
+    for (line, content_line) in unfold_lines(icalendar) {
+        let error = |reason| Error::ICalendar { line, reason };
+        if content_line.is_empty() {
+            continue;
+        }
+        let (name, parameters, value) = split_content_line(&content_line)
+            .ok_or(error("Malformed content line"))?;
+        let is_event = value.eq_ignore_ascii_case("VEVENT");
+        match (name.to_ascii_uppercase().as_str(), event.as_mut()) {
+            ("BEGIN", None) if is_event => event = Some(Event::new(line)),
+            ("BEGIN", None) => {}
+            ("BEGIN", Some(_)) if is_event => return Err(error("Event begins inside another event")),
+            ("BEGIN", Some(_)) => nesting += 1,
+            ("END", Some(_)) if nesting > 0 => nesting -= 1,
+            ("END", Some(_)) if is_event => {
+                if let Some(event) = event.take() {
+                    bank_holidays.extend(event.into_bank_holidays()?);
+                }
+            }
+            ("END", Some(_)) => return Err(error("Unexpected end of component inside event")),
+            ("END", None) if is_event => return Err(error("Event ends without beginning")),
+            ("END", None) => {}
+            (_, Some(_)) if nesting > 0 => {}
+            ("DTSTART", Some(event)) => {
+                event.start = Some(parse_date_value(parameters, value).ok_or(error("Invalid start date"))?);
+            }
+            ("DTEND", Some(event)) => {
+                event.end = Some((line, parse_date_value(parameters, value).ok_or(error("Invalid end date"))?));
+            }
+            ("DURATION", Some(event)) => event.duration = Some((line, value.to_owned())),
+            ("SUMMARY", Some(event)) => event.summary = unescape_text(value),
+            ("DESCRIPTION", Some(event)) => event.description = unescape_text(value),
+            _ => {}
+        }
+    }
+    if let Some(event) = event {
+        return Err(Error::ICalendar { line: event.line, reason: "Event does not end" });
+    }
+    Ok(bank_holidays)
+}
+
+/// Join folded lines, returning each content line with the line number it starts on.
+fn unfold_lines(icalendar: &str) -> Vec<(usize, String)> {
+    let mut content_lines: Vec<(usize, String)> = Vec::new();
+    for (index, line) in icalendar.lines().enumerate() {
+        if let Some(continuation) = line.strip_prefix([' ', '\t']) {
+            if let Some((_, content_line)) = content_lines.last_mut() {
+                content_line.push_str(continuation);
+                continue;
+            }
+        }
+        content_lines.push((index + 1, line.to_owned()));
+    }
+    content_lines
+}
+
+/// Split a content line into its name, parameters (with leading semicolon, if any) and value.
+fn split_content_line(content_line: &str) -> Option<(&str, &str, &str)> {
+    let name_end = content_line.find([';', ':'])?;
+    if name_end == 0 {
+        return None;
+    }
+    let mut quoted = false;
+    let value_start = content_line[name_end..].char_indices()
+        .find(|&(_, c)| {
+            if c == '"' {
+                quoted = !quoted;
+            }
+            c == ':' && !quoted
+        })
+        .map(|(index, _)| name_end + index)?;
+    Some((
+        &content_line[..name_end],
+        &content_line[name_end..value_start],
+        &content_line[value_start + 1..],
+    ))
+}
+
+/// Parse a `YYYYMMDD` date or identify a date with time.
+fn parse_date_value<Date: PlainDate>(parameters: &str, value: &str) -> Option<DateValue<Date>> {
+    let is_date = parameters.split(';')
+        .any(|parameter| parameter.eq_ignore_ascii_case("VALUE=DATE"));
+    if !is_date && value.contains('T') {
+        return Some(DateValue::DateTime);
+    }
+    if value.len() != 8 || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let year = value[..4].parse().ok()?;
+    let month = value[4..6].parse().ok()?;
+    let day = value[6..].parse().ok()?;
+    Date::try_from_components(year, month, day).ok().map(DateValue::Date)
+}
+
+/// Parse a duration of whole days or weeks, such as `P1D` or `P2W`, into a number of days.
+fn parse_duration_days(duration: &str) -> Option<i64> {
+    let duration = duration.strip_prefix('+').unwrap_or(duration);
+    let duration = duration.strip_prefix('P')?;
+    let (count, multiplier) = if let Some(days) = duration.strip_suffix('D') {
+        (days, 1)
+    } else {
+        (duration.strip_suffix('W')?, 7)
+    };
+    if count.is_empty() || !count.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let days = count.parse::<i64>().ok()?.checked_mul(multiplier)?;
+    (days > 0).then_some(days)
+}
+
+/// Reverse escaping of special characters in a text property value.
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => unescaped.push('\n'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_content_lines() {
+        assert_eq!(split_content_line("SUMMARY:Closed"), Some(("SUMMARY", "", "Closed")));
+        assert_eq!(
+            split_content_line("DTSTART;VALUE=DATE:20240101"),
+            Some(("DTSTART", ";VALUE=DATE", "20240101")),
+        );
+        assert_eq!(
+            split_content_line("DESCRIPTION;ALTREP=\"cid:notes\":Office closed: all day"),
+            Some(("DESCRIPTION", ";ALTREP=\"cid:notes\"", "Office closed: all day")),
+        );
+        assert_eq!(split_content_line("SUMMARY"), None);
+        assert_eq!(split_content_line(":value"), None);
+
+        assert_eq!(
+            unfold_lines("BEGIN:VEVENT\r\nSUMMARY:Long\r\n  title\r\n\tcontinued\r\nEND:VEVENT\r\n"),
+            [(1, "BEGIN:VEVENT".to_owned()), (2, "SUMMARY:Long titlecontinued".to_owned()), (5, "END:VEVENT".to_owned())],
+        );
+
+        assert_eq!(parse_duration_days("P1D"), Some(1));
+        assert_eq!(parse_duration_days("+P2W"), Some(14));
+        assert_eq!(parse_duration_days("PT12H"), None);
+        assert_eq!(parse_duration_days("P0D"), None);
+        assert_eq!(parse_duration_days("-P1D"), None);
+
+        assert_eq!(unescape_text("Christmas\\, Boxing\\; Day\\\\\\nBank holiday"), "Christmas, Boxing; Day\\\nBank holiday");
+        assert_eq!(unescape_text("Line\\Nbreak"), "Line\nbreak");
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn check_icalendar_import<Date: PlainDate>() {
+        // exported bank holidays can be imported back
+        #[cfg(feature = "icalendar")]
+        {
+            use crate::data_source::Cached;
+
+            let cached = Cached::<Date>::default().cached_data_source();
+            let icalendar = cached.to_icalendar(Division::NorthernIreland);
+            let imported = ICalendar::<Date>::new(icalendar, [Division::NorthernIreland])
+                .icalendar_data_source()
+                .expect("exported iCalendar should be imported")
+                .into_inner();
+            assert!(imported[&Division::EnglandAndWales].is_empty());
+            assert!(imported[&Division::Scotland].is_empty());
+            let cached = cached.into_inner();
+            assert_eq!(imported[&Division::NorthernIreland].len(), cached[&Division::NorthernIreland].len());
+            for (imported, cached) in imported[&Division::NorthernIreland].iter().zip(&cached[&Division::NorthernIreland]) {
+                assert_eq!(imported.provenance(), Provenance::Manual);
+                assert!(!imported.bunting());
+                assert_eq!(imported.date(), cached.date());
+                assert_eq!(imported.title(), cached.title());
+                assert_eq!(imported.notes(), cached.notes());
+            }
+        }
+
+        // multi-day events are expanded, timed events are skipped, nested components are ignored
+        // and exact repeats are dropped while different events on the same date are kept
+        let icalendar = concat!(
+            "BEGIN:VCALENDAR\r\n",
+            "VERSION:2.0\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART;VALUE=DATE:20241224\r\n",
+            "DTEND;VALUE=DATE:20250102\r\n",
+            "SUMMARY:Winter\r\n",
+            "  closure\r\n",
+            "DESCRIPTION:Offices closed\\, phones staffed\r\n",
+            "BEGIN:VALARM\r\n",
+            "DESCRIPTION:Reminder\r\n",
+            "END:VALARM\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART:20240614T090000Z\r\n",
+            "DTEND:20240614T170000Z\r\n",
+            "SUMMARY:Training\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART;VALUE=DATE:20240408\r\n",
+            "DURATION:P1W\r\n",
+            "SUMMARY:Spring closure\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART:20240101\r\n",
+            "SUMMARY:New Year\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART:20240101\r\n",
+            "SUMMARY:New Year\r\n",
+            "END:VEVENT\r\n",
+            "BEGIN:VEVENT\r\n",
+            "DTSTART:20240101\r\n",
+            "SUMMARY:Stocktake\r\n",
+            "END:VEVENT\r\n",
+            "END:VCALENDAR\r\n",
+        );
+        let imported = ICalendar::<Date>::new(icalendar, [Division::EnglandAndWales, Division::Scotland])
+            .icalendar_data_source()
+            .expect("iCalendar should be imported")
+            .into_inner();
+        assert!(imported[&Division::NorthernIreland].is_empty());
+        let bank_holidays = &imported[&Division::Scotland];
+        assert_eq!(bank_holidays, &imported[&Division::EnglandAndWales]);
+        assert_eq!(bank_holidays.len(), 2 + 7 + 9);
+        assert_eq!(bank_holidays[0].date().as_components(), (2024, 1, 1));
+        assert_eq!(bank_holidays[0].title(), "New Year");
+        assert_eq!(bank_holidays[1].date().as_components(), (2024, 1, 1));
+        assert_eq!(bank_holidays[1].title(), "Stocktake");
+        assert_eq!(bank_holidays[2].date().as_components(), (2024, 4, 8));
+        assert_eq!(bank_holidays[8].date().as_components(), (2024, 4, 14));
+        assert_eq!(bank_holidays[9].date().as_components(), (2024, 12, 24));
+        assert_eq!(bank_holidays[17].date().as_components(), (2025, 1, 1));
+        assert!(bank_holidays[9..].iter().all(|bank_holiday| {
+            bank_holiday.title() == "Winter closure" && bank_holiday.notes() == "Offices closed, phones staffed"
+        }));
+
+        // malformed input reports the line
+        for (icalendar, expected_line, expected_reason) in [
+            ("BEGIN:VEVENT\nDTSTART;VALUE=DATE:20240230\nEND:VEVENT\n", 2, "Invalid start date"),
+            ("BEGIN:VEVENT\nSUMMARY:Closed\nEND:VEVENT\n", 1, "Event has no start"),
+            ("BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART:20240101\n", 2, "Event does not end"),
+            ("BEGIN:VEVENT\nDTSTART:20240102\nDTEND:20240101\nEND:VEVENT\n", 3, "Event ends before it starts"),
+            ("BEGIN:VEVENT\nDTSTART:20240101\nDURATION:PT1H\nEND:VEVENT\n", 3, "Unsupported event duration"),
+            ("BEGIN:VEVENT\nDTSTART:20240101\nSUMMARY\nEND:VEVENT\n", 3, "Malformed content line"),
+            ("END:VEVENT\n", 1, "Event ends without beginning"),
+            ("BEGIN:VEVENT\nBEGIN:VEVENT\n", 2, "Event begins inside another event"),
+            ("BEGIN:VEVENT\nDTSTART:20240101\nDURATION:P5000000D\nEND:VEVENT\n", 3, "Event is too long"),
+            ("BEGIN:VEVENT\nDTSTART:20240101\nDTEND:20250101\nEND:VEVENT\n", 3, "Event is too long"),
+        ] {
+            let error = ICalendar::<Date>::new(icalendar, Division::all())
+                .icalendar_data_source()
+                .err()
+                .expect("malformed iCalendar should not be imported");
+            assert!(
+                matches!(error, Error::ICalendar { line, reason } if line == expected_line && reason == expected_reason),
+                "Unexpected error {error:?} for {icalendar:?}",
+            );
+        }
+
+        // single-day events in the last year with 8-digit dates do not need the following day,
+        // but longer ones fail if the date type cannot represent the next year
+        let imported = ICalendar::<Date>::new("BEGIN:VEVENT\nDTSTART;VALUE=DATE:99991231\nEND:VEVENT\n", [Division::Scotland])
+            .icalendar_data_source()
+            .expect("iCalendar should be imported")
+            .into_inner();
+        assert_eq!(imported[&Division::Scotland].len(), 1);
+        assert_eq!(imported[&Division::Scotland][0].date().as_components(), (9999, 12, 31));
+        let imported = ICalendar::<Date>::new("BEGIN:VEVENT\nDTSTART;VALUE=DATE:99991231\nDURATION:P2D\nEND:VEVENT\n", [Division::Scotland])
+            .icalendar_data_source();
+        if Date::try_from_components(10000, 1, 1).is_ok() {
+            assert_eq!(imported.expect("iCalendar should be imported").into_inner()[&Division::Scotland].len(), 2);
+        } else {
+            assert!(matches!(imported, Err(Error::ICalendar { line: 3, reason: "Event date out of range" })));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        check_icalendar_import::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_icalendar_import::<crate::dates::time::DateImpl>();
+    }
+}
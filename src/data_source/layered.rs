@@ -0,0 +1,189 @@
+use std::future::{poll_fn, Future};
+use std::pin::pin;
+use std::task::Poll;
+
+use crate::{Error, PlainDate};
+use crate::data_source::{DataSource, LoadDataSource, MergeStrategy};
+
+/// Loads bank holidays from a base source and a layer concurrently,
+/// then merges the layer on top of the base using [`DataSource::merge_with`].
+///
+/// Usually created with [`LoadDataSource::layer`] which can be chained to combine any number of sources;
+/// later layers take priority. By default, layers are merged with [`MergeStrategy::ByDate`]
+/// and the whole load fails if a layer fails, see [`LayerFailure`].
+///
+/// ```no_run
+/// use govuk_bank_holidays::data_source::{Computed, File, LayerFailure, LoadDataSource, MergeStrategy, Reqwest};
+///
+/// # #[cfg(feature = "chrono")]
+/// # async fn demo() {
+/// let loader = Reqwest::<chrono::NaiveDate>::default()
+///     .layer(File::new("closure-days.json"))
+///     .with_failure(LayerFailure::Skip)
+///     .layer(Computed::new(2020..=2040))
+///     .with_strategy(MergeStrategy::PreferOfficial);
+/// let data_source = loader.load_data_source().await;
+/// # }
+/// ```
+pub struct Layered<Base, Layer> {
+    base: Base,
+    layer: Layer,
+    strategy: MergeStrategy,
+    failure: LayerFailure,
+}
+
+/// What [`Layered`] does if its layer fails to load.
+/// NB: The whole load always fails if the base fails.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum LayerFailure {
+    /// Fail the whole load.
+    #[default]
+    Fail,
+    /// Log the failure and continue with only the base.
+    Skip,
+}
+
+impl<Base, Layer> Layered<Base, Layer> {
+    /// Create a loader that merges `layer` on top of `base` by date, failing if either fails.
+    #[inline]
+    pub const fn new(base: Base, layer: Layer) -> Self {
+        Self {
+            base,
+            layer,
+            strategy: MergeStrategy::ByDate,
+            failure: LayerFailure::Fail,
+        }
+    }
+
+    /// Same loader merging the layer using given [`MergeStrategy`].
+    #[inline]
+    pub fn with_strategy(self, strategy: MergeStrategy) -> Self {
+        Self { strategy, ..self }
+    }
+
+    /// Same loader with given behaviour if the layer fails.
+    #[inline]
+    pub fn with_failure(self, failure: LayerFailure) -> Self {
+        Self { failure, ..self }
+    }
+}
+
+impl<Date, Base, Layer> LoadDataSource<Date> for Layered<Base, Layer>
+where
+    Date: PlainDate,
+    Base: LoadDataSource<Date>,
+    Layer: LoadDataSource<Date>,
+{
+    async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
+        let (base, layer) = join(self.base.load_data_source(), self.layer.load_data_source()).await;
+        let mut data_source = base?;
+        match layer {
+            Ok(mut layer) => {
+                data_source.sort();
+                layer.sort();
+                data_source.merge_with(layer, self.strategy);
+            }
+            Err(error) if self.failure == LayerFailure::Skip => {
+                tracing::warn!("Skipping layer of bank holidays from {}: {error}", self.layer.name());
+            }
+            Err(error) => return Err(error),
+        }
+        Ok(data_source)
+    }
+
+    fn name(&self) -> String {
+        format!("{} + {}", self.base.name(), self.layer.name())
+    }
+}
+
+/// Poll both futures concurrently, returning their outputs once both are ready.
+async fn join<A: Future, B: Future>(a: A, b: B) -> (A::Output, B::Output) {
+    let mut a = pin!(a);
+    let mut b = pin!(b);
+    let mut a_output = None;
+    let mut b_output = None;
+    poll_fn(|context| {
+        if a_output.is_none() {
+            if let Poll::Ready(output) = a.as_mut().poll(context) {
+                a_output = Some(output);
+            }
+        }
+        if b_output.is_none() {
+            if let Poll::Ready(output) = b.as_mut().poll(context) {
+                b_output = Some(output);
+            }
+        }
+        match (a_output.take(), b_output.take()) {
+            (Some(a_output), Some(b_output)) => Poll::Ready((a_output, b_output)),
+            (a_pending, b_pending) => {
+                a_output = a_pending;
+                b_output = b_pending;
+                Poll::Pending
+            }
+        }
+    }).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    async fn check_layered<Date: PlainDate>() {
+        use crate::{Division, Provenance};
+        use crate::data_source::{Cached, Computed, Special};
+        use crate::data_source::testing::Failing;
+
+        // predicted years are added without overriding official ones
+        let loader = Cached::<Date>::default()
+            .layer(Failing::new("unavailable"))
+            .with_failure(LayerFailure::Skip)
+            .layer(Computed::new(2020..=2040))
+            .with_strategy(MergeStrategy::PreferOfficial);
+        assert_eq!(loader.name(), "Cached + Failing + Computed");
+        let data_source = loader.load_data_source().await
+            .expect("layers should load");
+        let first_year = if cfg!(feature = "historical") { 1978 } else { 2012 };
+        assert_eq!(data_source.coverage(Division::EnglandAndWales), Some(first_year..=2040));
+        let bank_holidays = data_source.into_inner();
+        let provenance_in_year = |year: i32| {
+            bank_holidays[&Division::EnglandAndWales].iter()
+                .filter(|bank_holiday| bank_holiday.date().year() == year)
+                .map(|bank_holiday| bank_holiday.provenance())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(provenance_in_year(2022), [Provenance::Official; 10]);
+        assert_eq!(provenance_in_year(2040), [Provenance::Predicted; 8]);
+
+        // later layers override earlier ones on the same date
+        let data_source = Computed::<Date>::new(2012..=2012)
+            .layer(Special::default())
+            .load_data_source().await
+            .expect("layers should load")
+            .into_inner();
+        let bank_holiday = data_source[&Division::Scotland].iter()
+            .find(|bank_holiday| bank_holiday.date().as_components() == (2012, 6, 5))
+            .expect("special bank holiday should be included");
+        assert_eq!(bank_holiday.provenance(), Provenance::Manual);
+
+        // failures of required layers or the base fail the whole load
+        let loader = Cached::<Date>::default().layer(Failing::new("unavailable"));
+        assert!(matches!(loader.load_data_source().await, Err(Error::Generic("unavailable"))));
+        let loader = Failing::<Date>::new("unavailable")
+            .layer(Cached::default())
+            .with_failure(LayerFailure::Skip);
+        assert!(matches!(loader.load_data_source().await, Err(Error::Generic("unavailable"))));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn chrono() {
+        check_layered::<crate::dates::chrono::DateImpl>().await;
+    }
+
+    #[cfg(feature = "time")]
+    #[tokio::test]
+    async fn time() {
+        check_layered::<crate::dates::time::DateImpl>().await;
+    }
+}
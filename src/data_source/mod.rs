@@ -1,18 +1,39 @@
 //! Utilities for load and parsing bank holidays from GOV.UK.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops::RangeInclusive;
 
 use serde::{de, de::MapAccess, Deserialize, Deserializer, ser::SerializeMap, Serialize, Serializer};
 
-use crate::{BankHoliday, Division, Error, PlainDate};
+use crate::{BankHoliday, Division, Error, PlainDate, Provenance};
 
 mod cached;
+mod computed;
+mod csv;
+mod diff;
+mod disk_cache;
+mod fallback;
+mod file;
+mod icalendar;
+mod layered;
 mod reqwest;
+mod special;
+#[cfg(test)]
+pub(crate) mod testing;
 
 pub use cached::Cached;
-pub use reqwest::Reqwest;
+pub use computed::Computed;
+pub use diff::{ChangedHoliday, DataSourceDiff, DivisionDiff};
+pub use disk_cache::DiskCache;
+pub use fallback::Fallback;
+pub use file::File;
+pub use icalendar::ICalendar;
+pub use layered::{LayerFailure, Layered};
+pub use reqwest::{Fetched, Reqwest, ReqwestBuilder, RetryPolicy};
+pub use special::Special;
+pub(crate) use special::is_special;
 
 /// A trait to allow clients of the library to load bank holidays by other means.
 pub trait LoadDataSource<Date: PlainDate> {
@@ -20,6 +41,43 @@ pub trait LoadDataSource<Date: PlainDate> {
     #[allow(async_fn_in_trait)]
     // #[expect(async_fn_in_trait, reason = "auto trait bounds do not need specifying inside this library")]
     async fn load_data_source(&self) -> Result<DataSource<Date>, Error>;
+
+    /// Name of this source of bank holidays, used in logging.
+    /// Defaults to the name of the implementing type.
+    fn name(&self) -> String {
+        let name = std::any::type_name::<Self>();
+        let name = name.split_once('<').map_or(name, |(name, _generics)| name);
+        name.rsplit("::").next().unwrap_or(name).to_owned()
+    }
+
+    /// Load a [`DataSource`] along with the name of the source that provided it, or return an [`Error`].
+    /// Combinators, such as [`Fallback`], name the inner source that succeeded.
+    #[allow(async_fn_in_trait)]
+    async fn load_named_data_source(&self) -> Result<(DataSource<Date>, String), Error> {
+        self.load_data_source().await
+            .map(|data_source| (data_source, self.name()))
+    }
+
+    /// Try this source first and, if it fails, load from `other`.
+    /// Can be chained to try several sources in order.
+    #[inline]
+    fn or_else<Other: LoadDataSource<Date>>(self, other: Other) -> Fallback<Self, Other>
+    where
+        Self: Sized,
+    {
+        Fallback::new(self, other)
+    }
+
+    /// Load both this source and `layer`, merging bank holidays from `layer` on top.
+    /// Can be chained to combine several sources, with later layers taking priority.
+    /// See [`Layered`] for how merging and failures of `layer` are handled.
+    #[inline]
+    fn layer<Layer: LoadDataSource<Date>>(self, layer: Layer) -> Layered<Self, Layer>
+    where
+        Self: Sized,
+    {
+        Layered::new(self, layer)
+    }
 }
 
 /// Represents a mapping of “divisions” to bank holidays.
@@ -48,6 +106,15 @@ impl<Date: PlainDate> DataSource<Date> {
         self.holiday_map
     }
 
+    /// Range of years with known bank holidays in given `division`, from the first to the last.
+    /// Returns `None` if there are none.
+    pub fn coverage(&self, division: Division) -> Option<RangeInclusive<i32>> {
+        let bank_holidays = self.holiday_map.get(&division)?;
+        let first = bank_holidays.iter().min()?;
+        let last = bank_holidays.iter().max()?;
+        Some(first.date().year()..=last.date().year())
+    }
+
     /// Sort each division by date.
     pub fn sort(&mut self) {
         for events in self.holiday_map.values_mut() {
@@ -65,34 +132,31 @@ impl<Date: PlainDate> DataSource<Date> {
     /// Merge with another data source, division by division,
     /// with `other` data source overriding this one if the same date appears in both.
     /// Both are assumed to be sorted already.
+    #[inline]
     pub fn merge(&mut self, other: DataSource<Date>) {
+        self.merge_with(other, MergeStrategy::ByDate)
+    }
+
+    /// Merge with another data source, division by division, using given [`MergeStrategy`].
+    /// Both are assumed to be sorted already.
+    pub fn merge_with(&mut self, other: DataSource<Date>, strategy: MergeStrategy) {
         for (division, other_events) in other.holiday_map {
             if let Some(events) = self.holiday_map.get_mut(&division) {
+                let mut existing_events = std::mem::take(events);
                 let mut other_events = other_events;
-                let mut merged_events = Vec::with_capacity(events.len());
-                {
-                    let mut existing_events = events.drain(..).peekable();
-                    let mut other_events = other_events.drain(..).peekable();
-                    loop {
-                        let Some(existing_event) = existing_events.peek() else {
-                            merged_events.extend(other_events);
-                            break;
-                        };
-                        let Some(other_event) = other_events.peek() else {
-                            merged_events.extend(existing_events);
-                            break;
-                        };
-                        if existing_event.date() < other_event.date() {
-                            merged_events.push(existing_events.next().unwrap());
-                        } else {
-                            if existing_event.date() == other_event.date() {
-                                existing_events.next().unwrap();
-                            }
-                            merged_events.push(other_events.next().unwrap());
-                        }
-                    }
+                if strategy == MergeStrategy::PreferOfficial {
+                    let official_years: HashSet<i32> = existing_events.iter()
+                        .chain(other_events.iter())
+                        .filter(|event| event.provenance().is_official())
+                        .map(|event| event.date().year())
+                        .collect();
+                    let is_superseded = |event: &BankHoliday<Date>| {
+                        event.provenance() == Provenance::Predicted && official_years.contains(&event.date().year())
+                    };
+                    existing_events.retain(|event| !is_superseded(event));
+                    other_events.retain(|event| !is_superseded(event));
                 }
-                std::mem::swap(&mut merged_events, events);
+                *events = merge_by_date(existing_events, other_events);
             } else {
                 self.holiday_map.insert(division, other_events);
             }
@@ -100,6 +164,46 @@ impl<Date: PlainDate> DataSource<Date> {
     }
 }
 
+/// How [`DataSource::merge_with`] combines two data sources.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MergeStrategy {
+    /// Bank holidays from the other data source override those on the same date.
+    ByDate,
+    /// As with [`MergeStrategy::ByDate`], but predicted bank holidays from either data source are first discarded
+    /// in any year and division where there are official ones, regardless of which data source has them.
+    /// Useful when combining [`Computed`] bank holidays with those from GOV.UK whose dates may differ.
+    PreferOfficial,
+}
+
+/// Merge two sorted lists of bank holidays with `other_events` overriding `existing_events` on the same date.
+fn merge_by_date<Date: PlainDate>(
+    existing_events: Vec<BankHoliday<Date>>,
+    other_events: Vec<BankHoliday<Date>>,
+) -> Vec<BankHoliday<Date>> {
+    let mut merged_events = Vec::with_capacity(existing_events.len().max(other_events.len()));
+    let mut existing_events = existing_events.into_iter().peekable();
+    let mut other_events = other_events.into_iter().peekable();
+    loop {
+        let Some(existing_event) = existing_events.peek() else {
+            merged_events.extend(other_events);
+            break;
+        };
+        let Some(other_event) = other_events.peek() else {
+            merged_events.extend(existing_events);
+            break;
+        };
+        if existing_event.date() < other_event.date() {
+            merged_events.push(existing_events.next().unwrap());
+        } else {
+            if existing_event.date() == other_event.date() {
+                existing_events.next().unwrap();
+            }
+            merged_events.push(other_events.next().unwrap());
+        }
+    }
+    merged_events
+}
+
 impl<Date: PlainDate> Serialize for DataSource<Date> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         struct Representation<'a, Date: PlainDate> {
@@ -214,6 +318,8 @@ mod tests {
         }"#;
         let source2 = DataSource::<Date>::try_from_json(source2)
             .expect("data source should be valid");
+        assert_eq!(source1.coverage(Division::Scotland), Some(2022..=2022));
+        assert_eq!(source1.coverage(Division::EnglandAndWales), None);
         source1.sort();
         source1.merge(source2);
         let source = source1.into_inner();
@@ -238,16 +344,55 @@ mod tests {
         assert!(DataSource::<Date>::try_from_json(source3).is_err());
     }
 
+    fn check_merge_preferring_official<Date: PlainDate>() {
+        let count_by_provenance = |data_source: &DataSource<Date>, year: i32| {
+            let bank_holidays = &data_source.holiday_map[&Division::EnglandAndWales];
+            let official = bank_holidays.iter()
+                .filter(|holiday| holiday.date().year() == year && holiday.provenance() == Provenance::Official)
+                .count();
+            let predicted = bank_holidays.iter()
+                .filter(|holiday| holiday.date().year() == year && holiday.provenance() == Provenance::Predicted)
+                .count();
+            (official, predicted)
+        };
+
+        // official bank holidays override predicted ones…
+        let mut data_source = Computed::<Date>::new(2021..=2030).computed_data_source()
+            .expect("bank holidays should be computed");
+        data_source.merge_with(Cached::default().cached_data_source(), MergeStrategy::PreferOfficial);
+        assert_eq!(count_by_provenance(&data_source, 2012), (9, 0));
+        assert_eq!(count_by_provenance(&data_source, 2022), (10, 0)); // includes 2 special bank holidays
+        assert_eq!(count_by_provenance(&data_source, 2028), (8, 0));
+        assert_eq!(count_by_provenance(&data_source, 2029), (0, 8));
+
+        // …regardless of which is merged into the other
+        let mut data_source = Cached::default().cached_data_source();
+        data_source.merge_with(
+            Computed::<Date>::new(2021..=2030).computed_data_source().expect("bank holidays should be computed"),
+            MergeStrategy::PreferOfficial,
+        );
+        assert_eq!(count_by_provenance(&data_source, 2022), (10, 0));
+        assert_eq!(count_by_provenance(&data_source, 2029), (0, 8));
+
+        // whereas merging by date keeps predicted bank holidays where dates differ
+        let mut data_source = Computed::<Date>::new(2021..=2030).computed_data_source()
+            .expect("bank holidays should be computed");
+        data_source.merge(Cached::default().cached_data_source());
+        assert_eq!(count_by_provenance(&data_source, 2022), (10, 1));
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn chrono_data_source() {
         check_data_source::<crate::dates::chrono::DateImpl>();
+        check_merge_preferring_official::<crate::dates::chrono::DateImpl>();
     }
 
     #[cfg(feature = "time")]
     #[test]
     fn time_data_source() {
         check_data_source::<crate::dates::time::DateImpl>();
+        check_merge_preferring_official::<crate::dates::time::DateImpl>();
     }
 
     #[cfg(any(feature = "chrono", feature = "time"))]
@@ -1,43 +1,561 @@
 use std::marker::PhantomData;
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::Duration;
+
+use reqwest::{Client, Response, StatusCode};
+use reqwest::header::{ETAG, HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
 
 use crate::{Error, PlainDate};
 use crate::data_source::{DataSource, LoadDataSource};
 
 /// Loads bank holidays from a URL in JSON format.
 /// Uses the `reqwest` client.
-pub struct Reqwest<'a, Date: PlainDate> {
-    url: &'a str,
+///
+/// Use [`Reqwest::builder`] to configure timeouts, retries, a maximum response size or an existing client.
+/// Responses without a successful status code fail with [`Error::HttpStatus`].
+///
+/// The `ETag` and `Last-Modified` headers of the last successful response are remembered
+/// so that [`Reqwest::load_if_modified`] can avoid downloading unchanged bank holidays again.
+pub struct Reqwest<Date: PlainDate> {
+    url: String,
+    /// NB: Unless an existing client is used, the default client is built on first use
+    /// so that failing to build it is an error when loading rather than a panic.
+    client: OnceLock<Client>,
+    timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    max_response_size: Option<u64>,
+    validators: Mutex<Validators>,
     _phantom: PhantomData<fn() -> Date>,
 }
 
-impl<Date: PlainDate> Default for Reqwest<'static, Date> {
+/// Result of [`Reqwest::load_if_modified`].
+pub enum Fetched<Date: PlainDate> {
+    /// Bank holidays were downloaded because they changed or there was no previous response.
+    Modified(DataSource<Date>),
+    /// Bank holidays have not changed since the previous response so the existing [`DataSource`] is still current.
+    NotModified,
+}
+
+/// Headers of the last successful response used to make conditional requests.
+#[derive(Clone, Default)]
+struct Validators {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+}
+
+impl Validators {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            etag: headers.get(ETAG).cloned(),
+            last_modified: headers.get(LAST_MODIFIED).cloned(),
+        }
+    }
+
+    /// Replace validators that are present in a “not modified” response, keeping the others.
+    fn refresh_from_headers(&mut self, headers: &HeaderMap) {
+        let Self { etag, last_modified } = Self::from_headers(headers);
+        if etag.is_some() {
+            self.etag = etag;
+        }
+        if last_modified.is_some() {
+            self.last_modified = last_modified;
+        }
+    }
+}
+
+impl<Date: PlainDate> Default for Reqwest<Date> {
     #[inline]
     fn default() -> Self {
         Reqwest::new(crate::SOURCE_URL)
     }
 }
 
-impl<'a, Date: PlainDate> Reqwest<'a, Date> {
+impl<Date: PlainDate> Reqwest<Date> {
     /// Create a new `reqwest`-based client for loading bank holidays in JSON format for the given URL.
+    /// If the default `reqwest` client cannot be built, loading fails with [`Error::Reqwest`].
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            client: OnceLock::new(),
+            timeout: None,
+            retry_policy: RetryPolicy::default(),
+            max_response_size: None,
+            validators: Mutex::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a loader of bank holidays in JSON format for the given URL using an existing `reqwest` client.
+    pub fn with_client(url: impl Into<String>, client: Client) -> Self {
+        Self {
+            url: url.into(),
+            client: OnceLock::from(client),
+            timeout: None,
+            retry_policy: RetryPolicy::default(),
+            max_response_size: None,
+            validators: Mutex::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Start configuring a loader of bank holidays in JSON format for the given URL.
     #[inline]
-    pub const fn new(url: &'a str) -> Self {
-        Self { url, _phantom: PhantomData }
+    pub fn builder(url: impl Into<String>) -> ReqwestBuilder<Date> {
+        ReqwestBuilder {
+            url: url.into(),
+            client: None,
+            timeout: None,
+            connect_timeout: None,
+            retry_policy: RetryPolicy::default(),
+            max_response_size: None,
+            _phantom: PhantomData,
+        }
     }
-}
 
-impl<'a, Date: PlainDate> LoadDataSource<Date> for Reqwest<'a, Date> {
+    /// URL that bank holidays are loaded from.
     #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// `ETag` header of the last successful response, if any, or a newer one from a “not modified” response.
+    pub fn etag(&self) -> Option<String> {
+        self.lock_validators().etag.as_ref()
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_owned)
+    }
+
+    /// `Last-Modified` header of the last successful response, if any, or a newer one from a “not modified” response.
+    pub fn last_modified(&self) -> Option<String> {
+        self.lock_validators().last_modified.as_ref()
+            .and_then(|last_modified| last_modified.to_str().ok())
+            .map(str::to_owned)
+    }
+
+    /// Load bank holidays only if they changed since the last successful response,
+    /// sending its `ETag` and `Last-Modified` headers as `If-None-Match` and `If-Modified-Since`.
+    /// Without a previous response, this always downloads bank holidays.
+    /// Headers sent with a “not modified” response replace those remembered.
+    pub async fn load_if_modified(&self) -> Result<Fetched<Date>, Error> {
+        tracing::debug!("Loading bank holidays from {} if modified", self.url);
+        self.fetch_with_retries(true).await
+    }
+
+    /// Client used for requests, building the default one if necessary.
+    fn client(&self) -> Result<&Client, Error> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        let client = default_client_builder().build()?;
+        Ok(self.client.get_or_init(|| client))
+    }
+
+    fn lock_validators(&self) -> std::sync::MutexGuard<'_, Validators> {
+        self.validators.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Make requests until one succeeds or fails in a way that should not be retried.
+    async fn fetch_with_retries(&self, conditional: bool) -> Result<Fetched<Date>, Error> {
+        let mut retries = 0;
+        loop {
+            match self.fetch(conditional).await {
+                Err(error) if retries < self.retry_policy.max_retries && is_transient(&error) => {
+                    let backoff = self.retry_policy.backoff(retries);
+                    tracing::warn!("Failed to load bank holidays from {}, retrying in {backoff:?}: {error}", self.url);
+                    tokio::time::sleep(backoff).await;
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Make one request, without retrying, and remember its headers if successful.
+    async fn fetch(&self, conditional: bool) -> Result<Fetched<Date>, Error> {
+        let mut request = self.client()?.get(&self.url);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        let mut is_conditional = false;
+        if conditional {
+            let validators = self.lock_validators().clone();
+            if let Some(etag) = validators.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+                is_conditional = true;
+            }
+            if let Some(last_modified) = validators.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+                is_conditional = true;
+            }
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED && is_conditional {
+            tracing::debug!("Bank holidays from {} not modified", self.url);
+            self.lock_validators().refresh_from_headers(response.headers());
+            return Ok(Fetched::NotModified);
+        }
+        if !status.is_success() {
+            return Err(Error::HttpStatus(status));
+        }
+        let validators = Validators::from_headers(response.headers());
+        let json = self.read_body(response).await?;
+        let mut data_source = DataSource::try_from_json(json)?;
+        data_source.sort();
+        data_source.add_missing_divisions();
+        *self.lock_validators() = validators;
+        Ok(Fetched::Modified(data_source))
+    }
+
+    /// Read response body, failing if it exceeds the maximum size.
+    async fn read_body(&self, mut response: Response) -> Result<Vec<u8>, Error> {
+        let Some(max_response_size) = self.max_response_size else {
+            return Ok(response.bytes().await?.to_vec());
+        };
+        if response.content_length().is_some_and(|length| length > max_response_size) {
+            return Err(Error::ResponseTooLarge(max_response_size));
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if (body.len() + chunk.len()) as u64 > max_response_size {
+                return Err(Error::ResponseTooLarge(max_response_size));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+}
+
+impl<Date: PlainDate> LoadDataSource<Date> for Reqwest<Date> {
     async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
         tracing::debug!("Loading bank holidays from {}", self.url);
-        reqwest::get(self.url)
-            .await?
-            .json::<DataSource<Date>>()
-            .await
-            .map(|mut data_source| {
-                data_source.sort();
-                data_source.add_missing_divisions();
-                data_source
-            })
-            .map_err(Error::from)
+        match self.fetch_with_retries(false).await? {
+            Fetched::Modified(data_source) => Ok(data_source),
+            // NB: not possible as an unconditional request was made
+            Fetched::NotModified => Err(Error::HttpStatus(StatusCode::NOT_MODIFIED)),
+        }
+    }
+
+    #[inline]
+    fn name(&self) -> String {
+        format!("Reqwest {}", self.url)
+    }
+}
+
+/// Configures a [`Reqwest`] loader; created with [`Reqwest::builder`].
+pub struct ReqwestBuilder<Date: PlainDate> {
+    url: String,
+    client: Option<Client>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    max_response_size: Option<u64>,
+    _phantom: PhantomData<fn() -> Date>,
+}
+
+impl<Date: PlainDate> ReqwestBuilder<Date> {
+    /// Use an existing `reqwest` client, for example one configured with a proxy or extra root certificates.
+    ///
+    /// NB: A connect timeout cannot be applied to an existing client.
+    #[inline]
+    pub fn client(self, client: Client) -> Self {
+        Self { client: Some(client), ..self }
+    }
+
+    /// Time limit for each request, from connecting until the response body has been read.
+    #[inline]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self { timeout: Some(timeout), ..self }
+    }
+
+    /// Time limit for connecting to the server; only applies if an existing client is not used.
+    #[inline]
+    pub fn connect_timeout(self, connect_timeout: Duration) -> Self {
+        Self { connect_timeout: Some(connect_timeout), ..self }
+    }
+
+    /// How failed requests are retried; by default they are not.
+    #[inline]
+    pub fn retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self { retry_policy, ..self }
+    }
+
+    /// Largest response body accepted, in bytes; larger responses fail with [`Error::ResponseTooLarge`].
+    #[inline]
+    pub fn max_response_size(self, max_response_size: u64) -> Self {
+        Self { max_response_size: Some(max_response_size), ..self }
+    }
+
+    /// Create the configured loader; fails if a new client cannot be built.
+    pub fn build(self) -> Result<Reqwest<Date>, Error> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut client_builder = default_client_builder();
+                if let Some(connect_timeout) = self.connect_timeout {
+                    client_builder = client_builder.connect_timeout(connect_timeout);
+                }
+                client_builder.build()?
+            }
+        };
+        Ok(Reqwest {
+            url: self.url,
+            client: OnceLock::from(client),
+            timeout: self.timeout,
+            retry_policy: self.retry_policy,
+            max_response_size: self.max_response_size,
+            validators: Mutex::default(),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// How [`Reqwest`] retries requests that fail because of connection problems, timeouts,
+/// server errors or too many requests. Other failures are not retried.
+///
+/// The delay before each retry doubles, starting from the initial backoff, up to 1 minute.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+}
+
+impl RetryPolicy {
+    const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+    /// Retry up to `max_retries` times, waiting `initial_backoff` before the first retry.
+    #[inline]
+    pub const fn new(max_retries: u32, initial_backoff: Duration) -> Self {
+        Self { max_retries, initial_backoff }
+    }
+
+    /// Never retry; the default.
+    #[inline]
+    pub const fn none() -> Self {
+        Self::new(0, Duration::ZERO)
+    }
+
+    /// Delay before given retry, counting from 0.
+    fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .checked_mul(2_u32.saturating_pow(retry))
+            .unwrap_or(Self::MAX_BACKOFF)
+            .min(Self::MAX_BACKOFF)
+    }
+}
+
+fn default_client_builder() -> reqwest::ClientBuilder {
+    Client::builder()
+        .user_agent(concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")))
+}
+
+/// Whether a request might succeed if retried.
+fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::Reqwest(error) => !error.is_builder() && !error.is_redirect(),
+        Error::HttpStatus(status) => status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS,
+        _ => false,
+    }
+}
+
+#[cfg(all(test, any(feature = "chrono", feature = "time")))]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::task::JoinHandle;
+
+    use super::*;
+
+    /// Serve one canned HTTP response per connection, in order, from a local port.
+    /// Returns the base URL and a handle to the raw requests received.
+    async fn stub_server(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await
+            .expect("local port should be available");
+        let url = format!("http://{}", listener.local_addr().expect("local address should be known"));
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::with_capacity(responses.len());
+            for response in responses {
+                let (mut stream, _) = listener.accept().await
+                    .expect("connection should be accepted");
+                let mut request = Vec::new();
+                let mut buffer = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let length = stream.read(&mut buffer).await
+                        .expect("request should be read");
+                    if length == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buffer[..length]);
+                }
+                requests.push(String::from_utf8_lossy(&request).into_owned());
+                stream.write_all(response.as_bytes()).await
+                    .expect("response should be written");
+                stream.shutdown().await
+                    .expect("connection should be closed");
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    /// Canned HTTP response.
+    fn response(status: &str, headers: &[&str], body: &str) -> String {
+        let mut response = format!("HTTP/1.1 {status}\r\nConnection: close\r\nContent-Length: {}\r\n", body.len());
+        for header in headers {
+            response.push_str(header);
+            response.push_str("\r\n");
+        }
+        response.push_str("\r\n");
+        response.push_str(body);
+        response
+    }
+
+    // language=json
+    const JSON: &str = r#"{
+      "scotland": {
+        "division": "scotland",
+        "events": [
+          {
+            "date": "2022-12-26",
+            "title": "Boxing Day",
+            "notes": "",
+            "bunting": true
+          }
+        ]
+      }
+    }"#;
+
+    #[test]
+    fn backoff() {
+        let retry_policy = RetryPolicy::new(10, Duration::from_millis(500));
+        assert_eq!(retry_policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(retry_policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(retry_policy.backoff(7), Duration::from_secs(60));
+        assert_eq!(retry_policy.backoff(40), Duration::from_secs(60));
+        assert_eq!(RetryPolicy::none().backoff(0), Duration::ZERO);
+    }
+
+    async fn check_reqwest<Date: PlainDate>() {
+        use crate::Division;
+
+        // server errors are retried
+        let (url, server) = stub_server(vec![
+            response("503 Service Unavailable", &[], ""),
+            response("200 OK", &["Content-Type: application/json"], JSON),
+        ]).await;
+        let loader = Reqwest::<Date>::builder(format!("{url}/bank-holidays.json"))
+            .timeout(Duration::from_secs(5))
+            .connect_timeout(Duration::from_secs(5))
+            .retry_policy(RetryPolicy::new(2, Duration::from_millis(1)))
+            .build()
+            .expect("loader should build");
+        assert_eq!(loader.name(), format!("Reqwest {url}/bank-holidays.json"));
+        let data_source = loader.load_data_source().await
+            .expect("bank holidays should load")
+            .into_inner();
+        assert_eq!(data_source[&Division::Scotland].len(), 1);
+        assert!(data_source[&Division::EnglandAndWales].is_empty());
+        let requests = server.await.expect("server should finish");
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("GET /bank-holidays.json HTTP/1.1\r\n"));
+        assert!(requests[0].to_ascii_lowercase().contains(&format!("user-agent: govuk-bank-holidays/{}\r\n", env!("CARGO_PKG_VERSION"))));
+
+        // client errors are not retried
+        let (url, server) = stub_server(vec![response("404 Not Found", &[], "")]).await;
+        let loader = Reqwest::<Date>::builder(url)
+            .retry_policy(RetryPolicy::new(2, Duration::from_millis(1)))
+            .build()
+            .expect("loader should build");
+        assert!(matches!(loader.load_data_source().await, Err(Error::HttpStatus(StatusCode::NOT_FOUND))));
+        assert_eq!(server.await.expect("server should finish").len(), 1);
+
+        // existing client is used and large responses are rejected
+        let (url, server) = stub_server(vec![response("200 OK", &[], JSON)]).await;
+        let client = Client::builder()
+            .user_agent("custom-agent")
+            .build()
+            .expect("client should build");
+        let loader = Reqwest::<Date>::builder(url)
+            .client(client)
+            .max_response_size(100)
+            .build()
+            .expect("loader should build");
+        assert!(matches!(loader.load_data_source().await, Err(Error::ResponseTooLarge(100))));
+        let requests = server.await.expect("server should finish");
+        assert!(requests[0].to_ascii_lowercase().contains("user-agent: custom-agent\r\n"));
+
+        let (url, server) = stub_server(vec![response("200 OK", &[], JSON)]).await;
+        let loader = Reqwest::<Date>::builder(url)
+            .max_response_size(JSON.len() as u64)
+            .build()
+            .expect("loader should build");
+        assert!(loader.load_data_source().await.is_ok());
+        server.await.expect("server should finish");
+
+        // default client is built when first used
+        let (url, server) = stub_server(vec![response("200 OK", &[], JSON)]).await;
+        let loader = Reqwest::<Date>::new(url);
+        assert!(loader.client.get().is_none());
+        assert!(loader.load_data_source().await.is_ok());
+        assert!(loader.client.get().is_some());
+        server.await.expect("server should finish");
+    }
+
+    async fn check_conditional<Date: PlainDate>() {
+        const ETAG_HEADER: &str = "ETag: \"v1\"";
+        const LAST_MODIFIED_HEADER: &str = "Last-Modified: Mon, 06 Jan 2025 09:00:00 GMT";
+
+        let (url, server) = stub_server(vec![
+            response("200 OK", &[ETAG_HEADER, LAST_MODIFIED_HEADER], JSON),
+            response("304 Not Modified", &["ETag: \"v1.1\""], ""),
+            response("200 OK", &["ETag: \"v2\""], JSON),
+            response("200 OK", &[], JSON),
+        ]).await;
+        let loader = Reqwest::<Date>::new(url);
+        assert!(loader.etag().is_none());
+
+        // first request is unconditional
+        assert!(matches!(loader.load_if_modified().await, Ok(Fetched::Modified(_))));
+        assert_eq!(loader.etag().as_deref(), Some("\"v1\""));
+        assert_eq!(loader.last_modified().as_deref(), Some("Mon, 06 Jan 2025 09:00:00 GMT"));
+
+        // then headers are sent and unchanged bank holidays are not downloaded,
+        // but validators sent with the “not modified” response replace earlier ones
+        assert!(matches!(loader.load_if_modified().await, Ok(Fetched::NotModified)));
+        assert_eq!(loader.etag().as_deref(), Some("\"v1.1\""));
+        assert_eq!(loader.last_modified().as_deref(), Some("Mon, 06 Jan 2025 09:00:00 GMT"));
+        assert!(matches!(loader.load_if_modified().await, Ok(Fetched::Modified(_))));
+        assert_eq!(loader.etag().as_deref(), Some("\"v2\""));
+        assert!(loader.last_modified().is_none());
+
+        // loading as a data source never sends headers
+        assert!(loader.load_data_source().await.is_ok());
+        assert!(loader.etag().is_none());
+
+        let requests: Vec<_> = server.await.expect("server should finish")
+            .into_iter()
+            .map(|request| request.to_ascii_lowercase())
+            .collect();
+        assert!(!requests[0].contains("if-none-match"));
+        assert!(!requests[0].contains("if-modified-since"));
+        assert!(requests[1].contains("if-none-match: \"v1\"\r\n"));
+        assert!(requests[1].contains("if-modified-since: mon, 06 jan 2025 09:00:00 gmt\r\n"));
+        assert!(requests[2].contains("if-none-match: \"v1.1\"\r\n"));
+        assert!(requests[2].contains("if-modified-since: mon, 06 jan 2025 09:00:00 gmt\r\n"));
+        assert!(!requests[3].contains("if-none-match"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test]
+    async fn chrono() {
+        check_reqwest::<crate::dates::chrono::DateImpl>().await;
+        check_conditional::<crate::dates::chrono::DateImpl>().await;
+    }
+
+    #[cfg(feature = "time")]
+    #[tokio::test]
+    async fn time() {
+        check_reqwest::<crate::dates::time::DateImpl>().await;
+        check_conditional::<crate::dates::time::DateImpl>().await;
     }
 }
@@ -0,0 +1,314 @@
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+use crate::{BankHoliday, Division, Error, PlainDate, Provenance};
+use crate::data_source::{DataSource, LoadDataSource};
+
+/// A one-off bank holiday or a regular one that was moved, both by royal proclamation.
+struct SpecialHoliday {
+    divisions: &'static [Division],
+    date: (i32, u8, u8),
+    title: &'static str,
+    notes: &'static str,
+    bunting: bool,
+    /// Date of the regular bank holiday that was moved, if any.
+    replaces: Option<(i32, u8, u8)>,
+}
+
+const ALL: &[Division] = &Division::all();
+const GREAT_BRITAIN: &[Division] = &[Division::EnglandAndWales, Division::Scotland];
+
+/// Known special bank holidays, in date order.
+const SPECIAL_HOLIDAYS: &[SpecialHoliday] = &[
+    SpecialHoliday {
+        divisions: ALL,
+        date: (1977, 6, 6),
+        title: "Spring bank holiday",
+        notes: "",
+        bunting: true,
+        replaces: Some((1977, 5, 30)),
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (1977, 6, 7),
+        title: "Queen’s Silver Jubilee",
+        notes: "Extra bank holiday",
+        bunting: true,
+        replaces: None,
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (1981, 7, 29),
+        title: "Royal wedding",
+        notes: "Extra bank holiday",
+        bunting: true,
+        replaces: None,
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (1995, 5, 8),
+        title: "Early May bank holiday (VE day)",
+        notes: "",
+        bunting: true,
+        replaces: Some((1995, 5, 1)),
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (1999, 12, 31),
+        title: "Millennium celebrations",
+        notes: "Extra bank holiday",
+        bunting: true,
+        replaces: None,
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (2002, 6, 3),
+        title: "Queen’s Golden Jubilee",
+        notes: "Extra bank holiday",
+        bunting: true,
+        replaces: None,
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (2002, 6, 4),
+        title: "Spring bank holiday",
+        notes: "",
+        bunting: true,
+        replaces: Some((2002, 5, 27)),
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (2011, 4, 29),
+        title: "Royal wedding",
+        notes: "Extra bank holiday",
+        bunting: true,
+        replaces: None,
+    },
+    SpecialHoliday {
+        divisions: GREAT_BRITAIN,
+        date: (2012, 6, 4),
+        title: "Spring bank holiday",
+        notes: "Substitute day",
+        bunting: true,
+        replaces: Some((2012, 5, 28)),
+    },
+    SpecialHoliday {
+        divisions: &[Division::NorthernIreland],
+        date: (2012, 6, 4),
+        title: "Spring bank holiday",
+        notes: "",
+        bunting: true,
+        replaces: Some((2012, 5, 28)),
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (2012, 6, 5),
+        title: "Queen’s Diamond Jubilee",
+        notes: "Extra bank holiday",
+        bunting: true,
+        replaces: None,
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (2020, 5, 8),
+        title: "Early May bank holiday (VE day)",
+        notes: "",
+        bunting: true,
+        replaces: Some((2020, 5, 4)),
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (2022, 6, 2),
+        title: "Spring bank holiday",
+        notes: "",
+        bunting: true,
+        replaces: Some((2022, 5, 30)),
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (2022, 6, 3),
+        title: "Platinum Jubilee bank holiday",
+        notes: "",
+        bunting: true,
+        replaces: None,
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (2022, 9, 19),
+        title: "Bank Holiday for the State Funeral of Queen Elizabeth II",
+        notes: "",
+        bunting: false,
+        replaces: None,
+    },
+    SpecialHoliday {
+        divisions: ALL,
+        date: (2023, 5, 8),
+        title: "Bank holiday for the coronation of King Charles III",
+        notes: "",
+        bunting: true,
+        replaces: None,
+    },
+];
+
+/// Built-in list of special bank holidays: one-off bank holidays such as for royal jubilees, weddings and funerals,
+/// and regular bank holidays that were moved to another date, both by royal proclamation.
+/// These cannot be predicted by [`Computed`](crate::data_source::Computed) so can be applied to correct it.
+///
+/// Bank holidays from this list are marked as [`Provenance::Manual`].
+pub struct Special<Date: PlainDate>(PhantomData<fn() -> Date>);
+
+impl<Date: PlainDate> Default for Special<Date> {
+    #[inline(always)]
+    fn default() -> Self {
+        Special(PhantomData)
+    }
+}
+
+impl<Date: PlainDate> Special<Date> {
+    /// Version of the built-in list, incremented whenever entries are added or corrected.
+    pub const VERSION: u32 = 2;
+
+    /// Create [`DataSource`] from only the special bank holidays.
+    /// Does not need asynchronous loading, but fails if any date cannot be represented.
+    pub fn special_data_source(&self) -> Result<DataSource<Date>, Error> {
+        let mut holiday_map: HashMap<_, Vec<_>> = HashMap::with_capacity(3);
+        for special_holiday in SPECIAL_HOLIDAYS {
+            for division in special_holiday.divisions {
+                holiday_map.entry(*division)
+                    .or_default()
+                    .push(special_holiday.bank_holiday()?);
+            }
+        }
+        let mut data_source = DataSource::new(holiday_map);
+        data_source.add_missing_divisions();
+        Ok(data_source)
+    }
+
+    /// Apply special bank holidays to a data source, adding one-off bank holidays and moving regular ones.
+    /// Only years that already have bank holidays in each division are changed,
+    /// and special bank holidays replace any others on the same date.
+    pub fn apply(&self, data_source: &mut DataSource<Date>) -> Result<(), Error> {
+        for (division, bank_holidays) in data_source.holiday_map.iter_mut() {
+            let known_years: HashSet<i32> = bank_holidays.iter()
+                .map(|bank_holiday| bank_holiday.date().year())
+                .collect();
+            let special_holidays = SPECIAL_HOLIDAYS.iter()
+                .filter(|special_holiday| {
+                    special_holiday.divisions.contains(division) && known_years.contains(&special_holiday.date.0)
+                });
+            for special_holiday in special_holidays {
+                let bank_holiday = special_holiday.bank_holiday()?;
+                let replaced_date = special_holiday.replaces
+                    .map(|(year, month, day)| Date::try_from_components(year, month, day))
+                    .transpose()?;
+                bank_holidays.retain(|existing| {
+                    existing.date() != bank_holiday.date() && Some(existing.date()) != replaced_date.as_ref()
+                });
+                bank_holidays.push(bank_holiday);
+            }
+        }
+        data_source.sort();
+        Ok(())
+    }
+}
+
+impl<Date: PlainDate> LoadDataSource<Date> for Special<Date> {
+    #[inline]
+    async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
+        self.special_data_source()
+    }
+}
+
+impl SpecialHoliday {
+    fn bank_holiday<Date: PlainDate>(&self) -> Result<BankHoliday<Date>, Error> {
+        let (year, month, day) = self.date;
+        let date = Date::try_from_components(year, month, day)?;
+        let bank_holiday = BankHoliday::new_with_notes(date, self.title.to_owned(), self.notes.to_owned())
+            .with_bunting(self.bunting)
+            .with_provenance(Provenance::Manual);
+        Ok(bank_holiday)
+    }
+}
+
+/// Whether a bank holiday with given date and title is in the built-in list of special bank holidays.
+pub(crate) fn is_special<Date: PlainDate>(date: &Date, title: &str) -> bool {
+    let date = date.as_components();
+    SPECIAL_HOLIDAYS.iter()
+        .any(|special_holiday| special_holiday.date == date && special_holiday.title == title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn special_holidays_in_order() {
+        assert!(SPECIAL_HOLIDAYS.windows(2).all(|pair| pair[0].date <= pair[1].date));
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn check_special<Date: PlainDate>() {
+        use crate::data_source::{Cached, Computed};
+
+        // computed bank holidays with special ones applied should match GOV.UK
+        let mut computed = Computed::<Date>::new(2012..=2028).computed_data_source()
+            .expect("bank holidays should be computed");
+        Special::default().apply(&mut computed)
+            .expect("special bank holidays should apply");
+        let computed = computed.into_inner();
+        let cached = Cached::<Date>::default().cached_data_source().into_inner();
+        for division in Division::all() {
+            let cached: Vec<_> = cached[&division].iter()
+                .filter(|holiday| holiday.date().year() >= 2012)
+                .collect();
+            assert_eq!(computed[&division].len(), cached.len(), "Unexpected number of bank holidays in {division:?}");
+            for (computed, cached) in computed[&division].iter().zip(cached) {
+                let expected_provenance = if cached.is_special() { Provenance::Manual } else { Provenance::Predicted };
+                assert_eq!(computed.provenance(), expected_provenance, "Unexpected provenance of {computed:?}");
+                if division == Division::Scotland && matches!(cached.date().as_components(), (2023, 1, 2) | (2023, 1, 3)) {
+                    // NB: GOV.UK labelled these the other way round to other years when 1st January was a Sunday
+                    assert_eq!(computed.date(), cached.date());
+                    continue;
+                }
+                // NB: GOV.UK did not put up bunting for Easter Monday in 2020
+                let bunting = if cached.date().as_components() == (2020, 4, 13) { false } else { computed.bunting() };
+                let computed = computed.clone()
+                    .with_bunting(bunting)
+                    .with_provenance(Provenance::Official);
+                assert_eq!(&computed, cached, "Unexpected bank holiday in {division:?}");
+            }
+        }
+
+        // special bank holidays only apply to known years
+        let mut computed = Computed::<Date>::new(1999..=2000).computed_data_source()
+            .expect("bank holidays should be computed");
+        Special::default().apply(&mut computed)
+            .expect("special bank holidays should apply");
+        let computed = computed.into_inner();
+        for division in Division::all() {
+            let bank_holidays = &computed[&division];
+            assert!(bank_holidays.iter().all(|bank_holiday| (1999..=2000).contains(&bank_holiday.date().year())));
+            assert!(bank_holidays.iter().any(|bank_holiday| {
+                bank_holiday.date().as_components() == (1999, 12, 31) && bank_holiday.is_special()
+            }));
+        }
+
+        let special = Special::<Date>::default().special_data_source()
+            .expect("special bank holidays should be valid")
+            .into_inner();
+        assert_eq!(special[&Division::Scotland].len(), SPECIAL_HOLIDAYS.len() - 1);
+        assert!(special[&Division::NorthernIreland].iter().all(BankHoliday::is_special));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        check_special::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_special::<crate::dates::time::DateImpl>();
+    }
+}
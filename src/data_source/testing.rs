@@ -0,0 +1,19 @@
+use std::marker::PhantomData;
+
+use crate::{Error, PlainDate};
+use crate::data_source::{DataSource, LoadDataSource};
+
+/// Loader that always fails with [`Error::Generic`] carrying the given message, for testing combinators.
+pub(crate) struct Failing<Date>(&'static str, PhantomData<fn() -> Date>);
+
+impl<Date: PlainDate> Failing<Date> {
+    pub(crate) fn new(message: &'static str) -> Self {
+        Failing(message, PhantomData)
+    }
+}
+
+impl<Date: PlainDate> LoadDataSource<Date> for Failing<Date> {
+    async fn load_data_source(&self) -> Result<DataSource<Date>, Error> {
+        Err(Error::Generic(self.0))
+    }
+}
@@ -45,22 +45,93 @@ pub trait PlainDate: Clone + Hash + Eq + Ord + fmt::Debug {
 
     /// Following date.
     fn next_day(&self) -> Self;
+
+    /// Number of days from `earlier` to this date; negative if `earlier` is actually later.
+    #[inline]
+    fn days_since(&self, earlier: &Self) -> i64 {
+        day_number(self) - day_number(earlier)
+    }
+
+    /// Date that is `days` days after this one, or before it if `days` is negative,
+    /// returning [`Error::InvalidDate`] if that cannot be represented.
+    fn try_add_days(&self, days: i64) -> Result<Self, Error> {
+        // keeps years within range of `i32`
+        const MAX_DAY_NUMBER: i64 = 365 * i32::MAX as i64;
+        let day_number = day_number(self).checked_add(days)
+            .filter(|day_number| day_number.abs() <= MAX_DAY_NUMBER)
+            .ok_or(Error::InvalidDate)?;
+        let (year, month, day) = components_from_day_number(day_number);
+        Self::try_from_components(year, month, day)
+    }
 }
 
 /// A day of the week from the Gregorian calendar.
+/// De/serialises as a lowercase name, also accepting three-letter abbreviations.
 #[allow(missing_docs)]
-#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 #[repr(u8)]
 pub enum Weekday {
+    #[serde(alias = "mon")]
     Monday = 1,
+    #[serde(alias = "tue")]
     Tuesday,
+    #[serde(alias = "wed")]
     Wednesday,
+    #[serde(alias = "thu")]
     Thursday,
+    #[serde(alias = "fri")]
     Friday,
+    #[serde(alias = "sat")]
     Saturday,
+    #[serde(alias = "sun")]
     Sunday,
 }
 
+impl Weekday {
+    /// All days of the week, starting with Monday.
+    pub const fn all() -> [Weekday; 7] {
+        [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+        ]
+    }
+}
+
+/// Number of days since 1970-01-01 (negative if before) for a date in the proleptic Gregorian calendar.
+/// Allows for date arithmetic using only [`PlainDate::as_components`].
+pub(crate) fn day_number<Date: PlainDate>(date: &Date) -> i64 {
+    // see https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let (year, month, day) = date.as_components();
+    let (month, day) = (month as i64, day as i64);
+    let year = year as i64 - if month <= 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year, month and day for a number of days since 1970-01-01; the inverse of [`day_number`].
+pub(crate) fn components_from_day_number(day_number: i64) -> (i32, u8, u8) {
+    // see https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let day_number = day_number + 719_468;
+    let era = day_number.div_euclid(146_097);
+    let day_of_era = day_number - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as i32, month as u8, day as u8)
+}
+
 /// Private newtype used internally for consistent de/serialisation.
 /// Easily constructed from inner date implementation and dereferences to it so has all `PlainDate` methods.
 #[derive(PartialEq, Eq, Hash, Clone)]
@@ -180,6 +251,8 @@ pub(crate) mod tests {
         assert_eq!(date.day(), 29);
         assert_eq!(date.weekday(), Weekday::Thursday);
         assert_eq!(&date.iso_date_string(), "2024-02-29");
+        assert_eq!(day_number(date), 19_782);
+        assert_eq!(components_from_day_number(19_782), (2024, 2, 29));
         let (year, month, day) = date.as_components();
         assert_eq!(year, 2024);
         assert_eq!(month, 2);
@@ -191,6 +264,28 @@ pub(crate) mod tests {
             (previous_day.iso_date_string().as_str(), next_day.iso_date_string().as_str()),
             ("2024-02-28", "2024-03-01"),
         );
+        assert_eq!((day_number(&previous_day), day_number(&next_day)), (19_781, 19_783));
+        assert_eq!(next_day.days_since(&previous_day), 2);
+        assert_eq!(previous_day.days_since(date), -1);
+        assert_eq!(date.try_add_days(1).expect("date should be valid"), next_day);
+        assert_eq!(date.try_add_days(-1).expect("date should be valid"), previous_day);
+        let next_year = date.try_add_days(366).expect("date should be valid");
+        assert_eq!(next_year.as_components(), (2025, 3, 1));
+        assert_eq!(next_year.days_since(date), 366);
+        assert!(date.try_add_days(i64::MAX).is_err());
+        assert!(date.try_add_days(i64::MIN).is_err());
+
+        for ((year, month, day), expected_day_number) in [
+            ((1970, 1, 1), 0),
+            ((1969, 12, 31), -1),
+            ((1900, 3, 1), -25_508),
+            ((2000, 3, 1), 11_017),
+        ] {
+            let date = Date::try_from_components(year, month, day)
+                .expect("date should be valid");
+            assert_eq!(day_number(&date), expected_day_number, "Unexpected day number for {date:?}");
+            assert_eq!(components_from_day_number(expected_day_number), (year, month, day));
+        }
     }
 
     /// Test internal wrapped date methods with implementation
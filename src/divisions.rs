@@ -32,6 +32,15 @@ impl Division {
             Division::NorthernIreland => "Northern Ireland",
         }
     }
+
+    /// Identifier of division as used in GOV.UK data, eg. `england-and-wales`.
+    pub const fn slug(self) -> &'static str {
+        match self {
+            Division::EnglandAndWales => "england-and-wales",
+            Division::Scotland => "scotland",
+            Division::NorthernIreland => "northern-ireland",
+        }
+    }
 }
 
 impl fmt::Display for Division {
@@ -39,3 +48,17 @@ impl fmt::Display for Division {
         f.write_str(self.name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugs_match_serde() {
+        for division in Division::all() {
+            let serialised = serde_json::to_value(division)
+                .expect("failed to serialise division");
+            assert_eq!(serialised.as_str(), Some(division.slug()));
+        }
+    }
+}
@@ -2,6 +2,7 @@ use thiserror::Error;
 
 /// Possible errors when handling dates and bank holidays.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Parsing error – bank holiday data could not be parsed.
     #[error("Parsing error")]
@@ -11,10 +12,50 @@ pub enum Error {
     #[error("Reqwest error")]
     Reqwest(#[from] reqwest::Error),
 
+    /// HTTP error – bank holiday data could not be loaded because the response did not have a successful status.
+    #[error("HTTP status {0}")]
+    HttpStatus(reqwest::StatusCode),
+
+    /// Bank holiday data could not be loaded because the response was larger than the given number of bytes.
+    #[error("Response larger than {0} bytes")]
+    ResponseTooLarge(u64),
+
+    /// IO error – bank holiday data could not be read or written.
+    #[error("IO error")]
+    Io(#[from] std::io::Error),
+
+    /// iCalendar error – bank holiday data could not be parsed from iCalendar format.
+    #[error("iCalendar error on line {line}: {reason}")]
+    ICalendar {
+        /// Line number, starting from 1, of the content line that could not be parsed.
+        line: usize,
+        /// Description of the problem.
+        reason: &'static str,
+    },
+
+    /// CSV error – bank holiday data could not be parsed from CSV format.
+    #[error("CSV error in row {row}, column {column}: {reason}")]
+    Csv {
+        /// Row number, starting from 1 for the heading row.
+        row: usize,
+        /// Column number, starting from 1.
+        column: usize,
+        /// Description of the problem.
+        reason: &'static str,
+    },
+
     /// Date is invalid.
     #[error("Invalid date")]
     InvalidDate,
 
+    /// Date is outside the years covered by known bank holidays.
+    #[error("Date is outside known bank holidays")]
+    OutsideCoverage,
+
+    /// No work day was found within the search limit, which suggests that [`WorkDays`](crate::WorkDays) is misconfigured.
+    #[error("No work day found within search limit")]
+    NoWorkDay,
+
     /// Another kind of error – useful for custom [`LoadDataSource`](crate::data_source::LoadDataSource) implementations.
     #[error("{0}")]
     Generic(&'static str),
@@ -37,6 +37,33 @@
 //! # }
 //! ```
 //!
+//! ## Work days
+//!
+//! Calendars use a Monday to Friday work week unless given another [`WorkDays`] implementation,
+//! such as a [`WeekdaySet`], a [`ScheduledWorkDays`] that changes over time, a [`RotaWorkDays`] shift pattern,
+//! a closure taking a date or a combination of these.
+//!
+//! ## Historical bank holidays
+//!
+//! The `"historical"` feature adds bank holidays from 1978, when the Early May bank holiday completed
+//! the schedule of the [Banking and Financial Dealings Act 1971](https://www.legislation.gov.uk/ukpga/1971/80/schedule/1),
+//! to the cached/embedded data which otherwise starts in 2012.
+//! These are generated from the rules in force in each year and known special bank holidays,
+//! so are marked as predicted or manual rather than official.
+//!
+//! ## iCalendar
+//!
+//! The `"icalendar"` feature allows bank holidays to be exported in iCalendar format (RFC 5545)
+//! for use in other calendar applications; see [`BankHolidayCalendar::to_icalendar`].
+//! All-day events in iCalendar format can be loaded as bank holidays without this feature;
+//! see [`data_source::ICalendar`].
+//!
+//! ## Shared calendar
+//!
+//! The `"shared"` feature adds [`SharedCalendar`], a handle to a calendar
+//! that can be cheaply cloned across threads and refreshed in the background by long-running services.
+//! Subscribers are notified with a [`DataSourceDiff`](crate::data_source::DataSourceDiff) when bank holidays change.
+//!
 //! ## Swappable date implementation
 //!
 //! - default or `"chrono"` feature will use the [`chrono`](https://crates.io/crates/chrono) crate
@@ -49,14 +76,24 @@ pub mod data_source;
 mod dates;
 mod divisions;
 mod errors;
+#[cfg(feature = "shared")]
+mod shared;
 mod work_days;
 
-pub use bank_holidays::BankHoliday;
-pub use calendar::BankHolidayCalendar;
+pub use bank_holidays::{BankHoliday, Provenance};
+pub use calendar::{
+    BankHolidayCalendar, BusinessDayConvention, Endpoints, HolidayIter, HolidayLookup, LoadReport, WorkDayIter,
+    WorkDayRangeIter,
+};
 pub use dates::{PlainDate, Weekday};
 pub use divisions::Division;
 pub use errors::Error;
-pub use work_days::{MonToFriWorkDays, WorkDays};
+#[cfg(feature = "shared")]
+pub use shared::SharedCalendar;
+pub use work_days::{
+    AndWorkDays, ExceptWorkDays, MonToFriWorkDays, NotWorkDays, OrWorkDays, RotaWorkDays, ScheduledWorkDays, WeekdaySet,
+    WorkDays,
+};
 
 /// Commonly-used items.
 ///
@@ -115,6 +152,35 @@ mod tests {
         test(BankHolidayCalendar::load().await);
     }
 
+    #[tokio::test]
+    async fn load_report() {
+        use data_source::{Computed, Reqwest};
+        use data_source::testing::Failing;
+
+        let before = std::time::SystemTime::now();
+        let loader = Computed::<Date>::new(2030..=2031);
+        let (calendar, report) = BankHolidayCalendar::load_or_cached(&loader, MonToFriWorkDays).await;
+        assert_eq!(report.source(), "Computed");
+        assert!(!report.is_fallback());
+        assert!(report.fallback_error().is_none());
+        assert!(report.loaded_at() >= before);
+        assert_eq!(report.coverage(), Some(2030..=2031));
+        assert_eq!(calendar.coverage(None), report.coverage());
+
+        let (calendar, report) = BankHolidayCalendar::load_or_cached(&Failing::new("unavailable"), MonToFriWorkDays).await;
+        assert_eq!(report.source(), "Cached");
+        assert!(report.is_fallback());
+        assert!(matches!(report.fallback_error(), Some(Error::Generic("unavailable"))));
+        assert_eq!(report.coverage(), BankHolidayCalendar::<Date, _>::cached().coverage(None));
+        test(calendar);
+
+        let (_calendar, report) = BankHolidayCalendar::load_or_cached(
+            &Reqwest::<Date>::new("http://127.0.0.1:9/bank-holidays.json"),
+            MonToFriWorkDays,
+        ).await;
+        assert!(matches!(report.fallback_error(), Some(Error::Reqwest(_))));
+    }
+
     #[tokio::test]
     async fn custom_work_days() {
         struct PartTime;
@@ -127,6 +193,14 @@ mod tests {
 
         let calendar = BankHolidayCalendar::cached_with(PartTime);
 
+        // counting falls back to checking each day
+        let start = Date::try_from_components(2024, 1, 1)
+            .expect("date should be valid");
+        let end = Date::try_from_components(2024, 1, 31)
+            .expect("date should be valid");
+        let count = calendar.count_work_days_between(&start, &end, Endpoints::Inclusive, Some(Division::Scotland));
+        assert_eq!(count, 13); // 1st and 2nd are bank holidays in Scotland
+
         // check days of whole month are correctly flagged as work days or not
         let mut date = Date::try_from_components(2024, 2, 1)
             .expect("date should be valid");
@@ -184,6 +258,45 @@ mod tests {
         assert_eq!(holidays.next(), Some((2015, 12, 28)));
     }
 
+    #[test]
+    fn holiday_iterators_are_double_ended() {
+        let calendar = BankHolidayCalendar::cached();
+        let date = Date::try_from_components(2016, 1, 5).unwrap();
+        for division in [None, Some(Division::Scotland)] {
+            let after = calendar.iter_holidays_after(&date, division);
+            assert_eq!(after.len(), calendar.holidays(division).iter().filter(|holiday| holiday.date() > &date).count());
+            let last = calendar.holidays(division).last().copied();
+            assert_eq!(after.clone().next_back(), last);
+            let forwards: Vec<_> = after.clone().collect();
+            let mut backwards: Vec<_> = after.rev().collect();
+            backwards.reverse();
+            assert_eq!(forwards, backwards);
+
+            let mut before = calendar.iter_holidays_before(&date, division);
+            let first = calendar.holidays(division).first().copied();
+            assert_eq!(before.next_back(), first);
+            let nearest = calendar.holidays(division).into_iter().rev().find(|holiday| holiday.date() < &date);
+            assert_eq!(before.next(), nearest);
+        }
+
+        let last = calendar.holidays(None).last().copied().expect("calendar should not be empty");
+        assert_eq!(calendar.iter_holidays_after(last.date(), None).next(), None);
+    }
+
+    #[test]
+    fn holiday_check_matches_holidays() {
+        let calendar = BankHolidayCalendar::cached();
+        let mut date = Date::try_from_components(2017, 12, 1).unwrap();
+        let end = Date::try_from_components(2023, 2, 1).unwrap();
+        while date < end {
+            for division in [None, Some(Division::EnglandAndWales), Some(Division::Scotland), Some(Division::NorthernIreland)] {
+                let expected = calendar.holidays(division).iter().any(|holiday| holiday.date() == &date);
+                assert_eq!(calendar.is_holiday(&date, division), expected, "Unexpected check of {date:?} in {division:?}");
+            }
+            date = PlainDate::next_day(&date);
+        }
+    }
+
     #[test]
     fn holiday_check() {
         let calendar = BankHolidayCalendar::cached();
@@ -229,6 +342,162 @@ mod tests {
         assert_eq!(work_days.next(), Some((2018, 1, 3)));
     }
 
+    #[test]
+    fn work_days_with_hints() {
+        use std::cell::Cell;
+
+        /// Works only on the 1st of each month, jumping ahead to it when hinted.
+        struct FirstOfMonth {
+            hinted: bool,
+            checks: Cell<usize>,
+        }
+
+        impl WorkDays<Date> for FirstOfMonth {
+            fn is_work_day(&self, date: &Date) -> bool {
+                self.checks.set(self.checks.get() + 1);
+                date.as_components().2 == 1
+            }
+
+            fn next_work_day_hint(&self, date: &Date) -> Date {
+                if !self.hinted {
+                    return PlainDate::next_day(date);
+                }
+                let (year, month, _) = date.as_components();
+                let (year, month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+                Date::try_from_components(year, month, 1).unwrap()
+            }
+
+            fn previous_work_day_hint(&self, date: &Date) -> Date {
+                if !self.hinted {
+                    return PlainDate::previous_day(date);
+                }
+                let (year, month, _) = PlainDate::previous_day(date).as_components();
+                Date::try_from_components(year, month, 1).unwrap()
+            }
+        }
+
+        let start = Date::try_from_components(2017, 11, 15).unwrap();
+        let expected = [(2017, 12, 1), (2018, 2, 1), (2018, 3, 1)]; // 1st January is a bank holiday
+        for hinted in [false, true] {
+            let calendar = BankHolidayCalendar::cached_with(FirstOfMonth { hinted, checks: Cell::new(0) });
+            let work_days: Vec<_> = calendar.iter_work_days_after(start, None)
+                .take(3)
+                .map(|date| date.as_components())
+                .collect();
+            assert_eq!(work_days, expected);
+            let checks = calendar.work_days().checks.get();
+            if hinted {
+                assert_eq!(checks, 4);
+            } else {
+                assert!(checks > 100);
+            }
+
+            let end = Date::try_from_components(2018, 3, 1).unwrap();
+            let mut work_days = calendar.iter_work_days_between(&start, &end, Endpoints::Inclusive, None)
+                .map(|date| date.as_components());
+            assert_eq!(work_days.next_back(), Some((2018, 3, 1)));
+            assert_eq!(work_days.next(), Some((2017, 12, 1)));
+            assert_eq!(work_days.next(), Some((2018, 2, 1)));
+            assert_eq!(work_days.next_back(), None);
+            assert_eq!(work_days.next(), None);
+            let work_days = calendar.iter_work_days_between(&start, &end, Endpoints::Exclusive, None)
+                .rev()
+                .map(|date| date.as_components())
+                .collect::<Vec<_>>();
+            assert_eq!(work_days, [(2018, 2, 1), (2017, 12, 1)]);
+        }
+    }
+
+    #[test]
+    fn work_days_with_stuck_hints() {
+        /// Works Monday to Friday but hints never move away from the given date.
+        struct Stuck;
+
+        impl WorkDays<Date> for Stuck {
+            fn is_work_day(&self, date: &Date) -> bool {
+                MonToFriWorkDays.is_work_day(date)
+            }
+
+            fn next_work_day_hint(&self, date: &Date) -> Date {
+                *date
+            }
+
+            fn previous_work_day_hint(&self, date: &Date) -> Date {
+                *date
+            }
+        }
+
+        let calendar = BankHolidayCalendar::cached_with(Stuck);
+        let friday = Date::try_from_components(2024, 1, 5).unwrap();
+        let monday = calendar.iter_work_days_after(friday, None).next();
+        assert_eq!(monday.map(|date| date.as_components()), Some((2024, 1, 8)));
+        let thursday = calendar.iter_work_days_before(friday, None).next();
+        assert_eq!(thursday.map(|date| date.as_components()), Some((2024, 1, 4)));
+        let end = Date::try_from_components(2024, 1, 31).unwrap();
+        assert_eq!(calendar.iter_work_days_between(&friday, &end, Endpoints::Inclusive, None).count(), 19);
+        assert_eq!(calendar.iter_work_days_between(&friday, &end, Endpoints::Inclusive, None).rev().count(), 19);
+    }
+
+    #[test]
+    fn work_days_search_limit() {
+        struct NeverWork;
+
+        impl WorkDays<Date> for NeverWork {
+            fn is_work_day(&self, _date: &Date) -> bool {
+                false
+            }
+        }
+
+        let calendar = BankHolidayCalendar::cached_with(NeverWork);
+        let date = Date::try_from_components(2020, 1, 1).unwrap();
+        assert_eq!(calendar.iter_work_days_after(date, None).next(), None);
+        assert!(matches!(calendar.try_add_work_days(date, 1, None), Err(Error::NoWorkDay)));
+        assert!(matches!(calendar.try_add_work_days(date, -1, None), Err(Error::NoWorkDay)));
+        assert_eq!(calendar.try_add_work_days(date, 0, None).ok(), Some(date));
+        assert!(matches!(calendar.try_adjust(&date, BusinessDayConvention::ModifiedFollowing, None), Err(Error::NoWorkDay)));
+        assert_eq!(calendar.try_adjust(&date, BusinessDayConvention::Unadjusted, None).ok(), Some(date));
+        let mut work_days = calendar.iter_work_days_before(date, None).with_search_limit(10);
+        assert_eq!(work_days.next(), None);
+        assert_eq!(work_days.next(), None);
+        let end = Date::try_from_components(2020, 12, 31).unwrap();
+        assert_eq!(calendar.iter_work_days_between(&date, &end, Endpoints::Inclusive, None).count(), 0);
+
+        // weekends are longer than a search limit of 1 day
+        let calendar = BankHolidayCalendar::cached();
+        let friday = Date::try_from_components(2024, 1, 5).unwrap();
+        assert_eq!(calendar.iter_work_days_after(friday, None).with_search_limit(1).next(), None);
+        let monday = calendar.iter_work_days_after(friday, None).with_search_limit(3).next();
+        assert_eq!(monday.map(|date| date.as_components()), Some((2024, 1, 8)));
+    }
+
+    #[test]
+    fn work_days_between() {
+        let calendar = BankHolidayCalendar::cached();
+        let start = Date::try_from_components(2017, 12, 19).unwrap();
+        let end = Date::try_from_components(2018, 1, 3).unwrap();
+        for division in [None, Some(Division::Scotland)] {
+            for endpoints in [Endpoints::Inclusive, Endpoints::Exclusive, Endpoints::IncludeStart, Endpoints::IncludeEnd] {
+                let work_days: Vec<_> = calendar.iter_work_days_between(&start, &end, endpoints, division).collect();
+                assert_eq!(work_days.len(), calendar.count_work_days_between(&start, &end, endpoints, division));
+                assert!(work_days.windows(2).all(|pair| pair[0] < pair[1]));
+                let mut reversed: Vec<_> = calendar.iter_work_days_between(&start, &end, endpoints, division)
+                    .rev()
+                    .collect();
+                reversed.reverse();
+                assert_eq!(work_days, reversed);
+            }
+        }
+        let work_days: Vec<_> = calendar.iter_work_days_between(&start, &end, Endpoints::Inclusive, None)
+            .map(|date| date.as_components())
+            .collect();
+        assert_eq!(work_days, [
+            (2017, 12, 19), (2017, 12, 20), (2017, 12, 21), (2017, 12, 22), (2017, 12, 27), (2017, 12, 28),
+            (2017, 12, 29), (2018, 1, 2), (2018, 1, 3),
+        ]);
+        assert_eq!(calendar.iter_work_days_between(&end, &start, Endpoints::Inclusive, None).next(), None);
+        assert_eq!(calendar.iter_work_days_between(&start, &start, Endpoints::Exclusive, None).next(), None);
+    }
+
     #[test]
     fn previous_work_day() {
         let calendar = BankHolidayCalendar::cached();
@@ -257,6 +526,145 @@ mod tests {
         assert_eq!(work_days.next(), Some((2017, 12, 19)));
     }
 
+    #[test]
+    fn add_work_days() {
+        let calendar = BankHolidayCalendar::cached();
+
+        let date = Date::try_from_components(2017, 12, 19).unwrap();
+        let expectation = [
+            (0, (2017, 12, 19)),
+            (1, (2017, 12, 20)),
+            (4, (2017, 12, 27)),
+            (7, (2018, 1, 2)),
+            (-1, (2017, 12, 18)),
+            (-5, (2017, 12, 12)),
+        ];
+        for (days, expected) in expectation {
+            let work_day = calendar.add_work_days(date, days, None);
+            assert_eq!(work_day.as_components(), expected, "Unexpected date {days} work days after {date:?}");
+            let work_day = calendar.subtract_work_days(date, -days, None);
+            assert_eq!(work_day.as_components(), expected, "Unexpected date {days} work days after {date:?}");
+        }
+
+        // non-work days are not counted themselves
+        let saturday = Date::try_from_components(2017, 12, 23).unwrap();
+        assert_eq!(calendar.add_work_days(saturday, 0, None).as_components(), (2017, 12, 23));
+        assert_eq!(calendar.add_work_days(saturday, 1, None).as_components(), (2017, 12, 27));
+        assert_eq!(calendar.subtract_work_days(saturday, 1, None).as_components(), (2017, 12, 22));
+
+        let date = Date::try_from_components(2017, 12, 29).unwrap();
+        let work_day = calendar.add_work_days(date, 1, Some(Division::EnglandAndWales));
+        assert_eq!(work_day.as_components(), (2018, 1, 2));
+        let work_day = calendar.add_work_days(date, 1, Some(Division::Scotland));
+        assert_eq!(work_day.as_components(), (2018, 1, 3));
+    }
+
+    #[test]
+    fn count_work_days_between() {
+        let calendar = BankHolidayCalendar::cached();
+
+        let start = Date::try_from_components(2017, 12, 19).unwrap();
+        let end = Date::try_from_components(2018, 1, 3).unwrap();
+        let expectation = [
+            (Endpoints::Inclusive, None, 9),
+            (Endpoints::Exclusive, None, 7),
+            (Endpoints::IncludeStart, None, 8),
+            (Endpoints::IncludeEnd, None, 8),
+            (Endpoints::Inclusive, Some(Division::Scotland), 8),
+        ];
+        for (endpoints, division, expected_count) in expectation {
+            let count = calendar.count_work_days_between(&start, &end, endpoints, division);
+            assert_eq!(count, expected_count, "Unexpected count of work days with {endpoints:?} in {division:?}");
+        }
+        assert_eq!(calendar.count_work_days_between(&end, &start, Endpoints::Inclusive, None), 0);
+        assert_eq!(calendar.count_work_days_between(&start, &start, Endpoints::Inclusive, None), 1);
+        assert_eq!(calendar.count_work_days_between(&start, &start, Endpoints::IncludeStart, None), 0);
+
+        // compare with walking through work days over a longer period
+        let start = Date::try_from_components(2013, 2, 3).unwrap();
+        let end = Date::try_from_components(2027, 11, 6).unwrap();
+        for division in [None, Some(Division::EnglandAndWales), Some(Division::Scotland), Some(Division::NorthernIreland)] {
+            let expected_count = calendar.iter_work_days_after(start, division)
+                .take_while(|date| date < &end)
+                .count();
+            let count = calendar.count_work_days_between(&start, &end, Endpoints::Exclusive, division);
+            assert_eq!(count, expected_count, "Unexpected count of work days in {division:?}");
+        }
+    }
+
+    #[test]
+    fn adjust() {
+        use BusinessDayConvention::*;
+
+        let calendar = BankHolidayCalendar::cached();
+
+        let expectation = [
+            // work days are unchanged
+            ((2017, 12, 22), Following, None, (2017, 12, 22)),
+            ((2017, 12, 22), ModifiedPreceding, None, (2017, 12, 22)),
+            // weekend before christmas
+            ((2017, 12, 23), Following, None, (2017, 12, 27)),
+            ((2017, 12, 23), ModifiedFollowing, None, (2017, 12, 27)),
+            ((2017, 12, 23), Preceding, None, (2017, 12, 22)),
+            ((2017, 12, 23), ModifiedPreceding, None, (2017, 12, 22)),
+            ((2017, 12, 23), Unadjusted, None, (2017, 12, 23)),
+            // easter weekend crossing month boundary
+            ((2018, 3, 31), Following, Some(Division::EnglandAndWales), (2018, 4, 3)),
+            ((2018, 3, 31), Following, Some(Division::Scotland), (2018, 4, 2)),
+            ((2018, 3, 31), ModifiedFollowing, Some(Division::EnglandAndWales), (2018, 3, 29)),
+            ((2018, 3, 31), ModifiedFollowing, Some(Division::Scotland), (2018, 3, 29)),
+            ((2018, 4, 1), Preceding, Some(Division::EnglandAndWales), (2018, 3, 29)),
+            ((2018, 4, 1), ModifiedPreceding, Some(Division::EnglandAndWales), (2018, 4, 3)),
+            ((2018, 4, 1), ModifiedPreceding, Some(Division::Scotland), (2018, 4, 2)),
+            ((2018, 4, 1), ModifiedPreceding, None, (2018, 4, 2)),
+        ];
+        for ((year, month, day), convention, division, expected) in expectation {
+            let date = Date::try_from_components(year, month, day).unwrap();
+            let adjusted = calendar.adjust(&date, convention, division);
+            assert_eq!(
+                adjusted.as_components(), expected,
+                "Unexpected adjustment of {date:?} using {convention:?} in {division:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn coverage() {
+        let calendar = BankHolidayCalendar::cached();
+        let first_year = if cfg!(feature = "historical") { 1978 } else { 2012 };
+        for division in [None, Some(Division::EnglandAndWales), Some(Division::Scotland), Some(Division::NorthernIreland)] {
+            assert_eq!(calendar.coverage(division), Some(first_year..=2028), "Unexpected coverage in {division:?}");
+        }
+
+        let date = Date::try_from_components(2022, 12, 26).unwrap();
+        let HolidayLookup::Holiday(bank_holiday) = calendar.lookup(&date, None) else {
+            panic!("{date:?} should be a bank holiday");
+        };
+        assert_eq!(bank_holiday.title(), "Boxing Day");
+        let date = Date::try_from_components(2022, 12, 28).unwrap();
+        assert_eq!(calendar.lookup(&date, None), HolidayLookup::NotHoliday);
+        assert_eq!(calendar.try_is_work_day(&date, None).ok(), Some(true));
+        let date = Date::try_from_components(2022, 11, 30).unwrap();
+        assert_eq!(calendar.lookup(&date, Some(Division::EnglandAndWales)), HolidayLookup::NotHoliday);
+        assert!(matches!(calendar.lookup(&date, Some(Division::Scotland)), HolidayLookup::Holiday(_)));
+        assert_eq!(calendar.try_is_work_day(&date, Some(Division::Scotland)).ok(), Some(false));
+
+        let date = Date::try_from_components(2035, 12, 25).unwrap();
+        assert!(!calendar.is_holiday(&date, None));
+        assert_eq!(calendar.lookup(&date, None), HolidayLookup::Unknown);
+        assert!(matches!(calendar.try_is_work_day(&date, None), Err(Error::OutsideCoverage)));
+        let date = Date::try_from_components(first_year - 1, 12, 26).unwrap();
+        assert_eq!(calendar.lookup(&date, None), HolidayLookup::Unknown);
+
+        let date = Date::try_from_components(2028, 12, 27).unwrap();
+        let work_day = calendar.try_add_work_days(date, 2, None)
+            .expect("date should be within coverage");
+        assert_eq!(work_day.as_components(), (2028, 12, 29));
+        assert!(matches!(calendar.try_add_work_days(date, 3, None), Err(Error::OutsideCoverage)));
+        let date = Date::try_from_components(2029, 1, 2).unwrap();
+        assert!(matches!(calendar.try_add_work_days(date, -3, None), Err(Error::OutsideCoverage)));
+    }
+
     fn holidays_2018_to_2022(bank_holiday: &BankHoliday<Date>) -> bool {
         bank_holiday.date().year() >= 2018 && bank_holiday.date().year() <= 2022
     }
@@ -0,0 +1,230 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::time::Duration;
+
+use arc_swap::ArcSwap;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::{BankHolidayCalendar, Error, PlainDate, WorkDays};
+use crate::data_source::{DataSource, DataSourceDiff, LoadDataSource};
+
+/// Handle to a [`BankHolidayCalendar`] that can be replaced while in use, for long-running services.
+/// Cheap to clone and, with thread-safe dates and [`WorkDays`], can be shared across threads.
+///
+/// Readers take a snapshot of the current calendar with [`SharedCalendar::current`] which never waits for a refresh.
+/// Refreshing builds a new calendar and swaps it in atomically; if loading fails, the previous calendar is kept.
+/// Use [`SharedCalendar::subscribe`] to be notified when refreshed bank holidays differ.
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// use govuk_bank_holidays::prelude::*;
+/// use govuk_bank_holidays::SharedCalendar;
+/// use govuk_bank_holidays::data_source::{LoadDataSource, Reqwest};
+///
+/// # #[cfg(feature = "chrono")]
+/// # async fn demo() {
+/// let shared = SharedCalendar::new(BankHolidayCalendar::<chrono::NaiveDate, _>::load().await);
+/// let loader = Arc::new(Reqwest::default());
+/// shared.spawn_refresh(Duration::from_secs(60 * 60), move || {
+///     let loader = loader.clone();
+///     async move { loader.load_data_source().await }
+/// });
+///
+/// let date = chrono::Local::now().date_naive();
+/// let is_work_day = shared.current().is_work_day(&date, Some(Division::Scotland));
+/// # }
+/// ```
+pub struct SharedCalendar<Date: PlainDate, W: WorkDays<Date>> {
+    inner: Arc<Inner<Date, W>>,
+}
+
+struct Inner<Date: PlainDate, W: WorkDays<Date>> {
+    calendar: ArcSwap<BankHolidayCalendar<Date, W>>,
+    work_days: W,
+    changes: watch::Sender<Arc<DataSourceDiff<Date>>>,
+    /// Held while replacing the calendar so that each difference is found and sent in the order of swaps.
+    replacing: Mutex<()>,
+}
+
+impl<Date: PlainDate, W: WorkDays<Date>> Clone for SharedCalendar<Date, W> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<Date: PlainDate, W: WorkDays<Date> + Clone> SharedCalendar<Date, W> {
+    /// Share given calendar; refreshed calendars will use the same [`WorkDays`].
+    pub fn new(calendar: BankHolidayCalendar<Date, W>) -> Self {
+        let work_days = calendar.work_days().clone();
+        Self {
+            inner: Arc::new(Inner {
+                calendar: ArcSwap::from_pointee(calendar),
+                work_days,
+                changes: watch::Sender::new(Arc::default()),
+                replacing: Mutex::default(),
+            }),
+        }
+    }
+
+    /// Snapshot of the current calendar, which is unaffected by later refreshes.
+    #[inline]
+    pub fn current(&self) -> Arc<BankHolidayCalendar<Date, W>> {
+        self.inner.calendar.load_full()
+    }
+
+    /// Subscribe to changes in bank holidays, as found by comparing each replacement calendar with the previous one.
+    /// The receiver is only notified when there are differences.
+    ///
+    /// NB: Only the latest differences are kept so a receiver that is not checked between replacements
+    /// will miss earlier ones; compare [snapshots](SharedCalendar::current) if that matters.
+    #[inline]
+    pub fn subscribe(&self) -> watch::Receiver<Arc<DataSourceDiff<Date>>> {
+        self.inner.changes.subscribe()
+    }
+
+    /// Replace the current calendar with one built from given bank holidays,
+    /// returning differences from the previous calendar.
+    /// Concurrent replacements take turns so subscribers are notified of differences in the same order.
+    pub fn replace(&self, data_source: DataSource<Date>) -> Arc<DataSourceDiff<Date>> {
+        let calendar = Arc::new(BankHolidayCalendar::new(data_source, self.inner.work_days.clone()));
+        let _replacing = self.inner.replacing.lock().unwrap_or_else(PoisonError::into_inner);
+        let previous = self.inner.calendar.swap(calendar.clone());
+        let diff = Arc::new(DataSourceDiff::between(previous.holiday_map(), calendar.holiday_map()));
+        if !diff.is_empty() {
+            tracing::info!("Bank holidays changed");
+            self.inner.changes.send_replace(diff.clone());
+        }
+        diff
+    }
+
+    /// Reload bank holidays from given source and replace the current calendar,
+    /// returning differences from the previous calendar.
+    /// If loading fails, the current calendar is kept and the error is returned.
+    pub async fn refresh<T: LoadDataSource<Date>>(&self, loader: &T) -> Result<Arc<DataSourceDiff<Date>>, Error> {
+        let data_source = loader.load_data_source().await?;
+        Ok(self.replace(data_source))
+    }
+
+    /// Spawn a `tokio` task that reloads bank holidays on the given interval using `load`,
+    /// which is called each time to produce a future that loads them.
+    /// Failures are logged and the current calendar is kept.
+    ///
+    /// The task stops once all handles to this calendar are dropped or when aborted using the returned handle.
+    pub fn spawn_refresh<F, Fut>(&self, interval: Duration, mut load: F) -> JoinHandle<()>
+    where
+        Date: Send + Sync + 'static,
+        W: Send + Sync + 'static,
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<DataSource<Date>, Error>> + Send + 'static,
+    {
+        let inner = Arc::downgrade(&self.inner);
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if Weak::strong_count(&inner) == 0 {
+                    break;
+                }
+                let result = load().await;
+                let Some(inner) = inner.upgrade() else {
+                    break;
+                };
+                match result {
+                    Ok(data_source) => {
+                        tracing::debug!("Refreshed bank holidays");
+                        SharedCalendar { inner }.replace(data_source);
+                    }
+                    Err(error) => tracing::warn!("Failed to refresh bank holidays, keeping previous ones: {error}"),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(all(test, any(feature = "chrono", feature = "time")))]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::{Division, MonToFriWorkDays};
+    use crate::data_source::Computed;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    async fn check_shared<Date: PlainDate + Send + Sync + 'static>() {
+        assert_send_sync::<SharedCalendar<Date, MonToFriWorkDays>>();
+
+        let shared = SharedCalendar::new(BankHolidayCalendar::<Date, _>::cached());
+        let snapshot = shared.current();
+        let cached_coverage = snapshot.coverage(None);
+        let mut changes = shared.subscribe();
+        assert!(changes.borrow_and_update().is_empty());
+
+        let loads = Arc::new(AtomicUsize::new(0));
+        let task = shared.spawn_refresh(Duration::from_secs(60), {
+            let loads = loads.clone();
+            move || {
+                let load = loads.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    match load {
+                        0 => Computed::new(2030..=2031).computed_data_source(),
+                        _ => Err(Error::Generic("unavailable")),
+                    }
+                }
+            }
+        });
+
+        // first refresh succeeds
+        tokio::time::sleep(Duration::from_secs(61)).await;
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        assert_eq!(shared.current().coverage(Some(Division::Scotland)), Some(2030..=2031));
+        assert_eq!(shared.clone().current().coverage(None), Some(2030..=2031));
+        // earlier snapshot is unaffected
+        assert_eq!(snapshot.coverage(None), cached_coverage);
+        // subscribers are notified of differences
+        assert!(changes.has_changed().expect("sender should exist"));
+        let diff = changes.borrow_and_update().clone();
+        let scotland = diff.division(Division::Scotland).expect("Scotland should have differences");
+        assert!(scotland.added().iter().all(|bank_holiday| (2030..=2031).contains(&bank_holiday.date().year())));
+        assert!(scotland.removed().iter().all(|bank_holiday| bank_holiday.date().year() < 2030));
+        assert!(!scotland.removed().is_empty());
+
+        // failed refresh keeps previous calendar
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+        assert_eq!(shared.current().coverage(None), Some(2030..=2031));
+        assert!(!changes.has_changed().expect("sender should exist"));
+
+        // manual refresh only notifies if bank holidays differ
+        let diff = shared.refresh(&Computed::new(2030..=2031)).await
+            .expect("bank holidays should be computed");
+        assert!(diff.is_empty());
+        assert!(!changes.has_changed().expect("sender should exist"));
+        let diff = shared.refresh(&Computed::new(2040..=2040)).await
+            .expect("bank holidays should be computed");
+        assert_eq!(shared.current().coverage(None), Some(2040..=2040));
+        assert!(changes.has_changed().expect("sender should exist"));
+        assert_eq!(*changes.borrow_and_update(), diff);
+
+        // task stops once handles are dropped
+        drop(shared);
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        assert!(task.is_finished());
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[tokio::test(start_paused = true)]
+    async fn chrono() {
+        check_shared::<crate::dates::chrono::DateImpl>().await;
+    }
+
+    #[cfg(feature = "time")]
+    #[tokio::test(start_paused = true)]
+    async fn time() {
+        check_shared::<crate::dates::time::DateImpl>().await;
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{PlainDate, WorkDays};
+use crate::dates::day_number;
+
+/// Work days according to both of two patterns; see [`WorkDays::and`].
+#[derive(Debug, Copy, Clone)]
+pub struct AndWorkDays<A, B> {
+    a: A,
+    b: B,
+}
+
+/// Work days according to either of two patterns; see [`WorkDays::or`].
+#[derive(Debug, Copy, Clone)]
+pub struct OrWorkDays<A, B> {
+    a: A,
+    b: B,
+}
+
+/// Work days according to one pattern that are not work days according to another; see [`WorkDays::except`].
+#[derive(Debug, Copy, Clone)]
+pub struct ExceptWorkDays<A, B> {
+    a: A,
+    b: B,
+}
+
+/// Days that are not work days according to a pattern; see [`WorkDays::not`].
+#[derive(Debug, Copy, Clone)]
+pub struct NotWorkDays<A> {
+    a: A,
+}
+
+impl<A, B> AndWorkDays<A, B> {
+    #[inline]
+    pub(crate) const fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A, B> OrWorkDays<A, B> {
+    #[inline]
+    pub(crate) const fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A, B> ExceptWorkDays<A, B> {
+    #[inline]
+    pub(crate) const fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A> NotWorkDays<A> {
+    #[inline]
+    pub(crate) const fn new(a: A) -> Self {
+        Self { a }
+    }
+}
+
+impl<Date: PlainDate, A: WorkDays<Date>, B: WorkDays<Date>> WorkDays<Date> for AndWorkDays<A, B> {
+    #[inline]
+    fn is_work_day(&self, date: &Date) -> bool {
+        self.a.is_work_day(date) && self.b.is_work_day(date)
+    }
+
+    // neither pattern has work days before its own hint so both cannot until the later one
+
+    fn next_work_day_hint(&self, date: &Date) -> Date {
+        self.a.next_work_day_hint(date).max(self.b.next_work_day_hint(date))
+    }
+
+    fn previous_work_day_hint(&self, date: &Date) -> Date {
+        self.a.previous_work_day_hint(date).min(self.b.previous_work_day_hint(date))
+    }
+}
+
+impl<Date: PlainDate, A: WorkDays<Date>, B: WorkDays<Date>> WorkDays<Date> for OrWorkDays<A, B> {
+    #[inline]
+    fn is_work_day(&self, date: &Date) -> bool {
+        self.a.is_work_day(date) || self.b.is_work_day(date)
+    }
+
+    // either pattern could have a work day from its own hint so only the nearer one can be skipped to
+
+    fn next_work_day_hint(&self, date: &Date) -> Date {
+        self.a.next_work_day_hint(date).min(self.b.next_work_day_hint(date))
+    }
+
+    fn previous_work_day_hint(&self, date: &Date) -> Date {
+        self.a.previous_work_day_hint(date).max(self.b.previous_work_day_hint(date))
+    }
+}
+
+impl<Date: PlainDate, A: WorkDays<Date>, B: WorkDays<Date>> WorkDays<Date> for ExceptWorkDays<A, B> {
+    #[inline]
+    fn is_work_day(&self, date: &Date) -> bool {
+        self.a.is_work_day(date) && !self.b.is_work_day(date)
+    }
+
+    #[inline]
+    fn next_work_day_hint(&self, date: &Date) -> Date {
+        self.a.next_work_day_hint(date)
+    }
+
+    #[inline]
+    fn previous_work_day_hint(&self, date: &Date) -> Date {
+        self.a.previous_work_day_hint(date)
+    }
+}
+
+impl<Date: PlainDate, A: WorkDays<Date>> WorkDays<Date> for NotWorkDays<A> {
+    #[inline]
+    fn is_work_day(&self, date: &Date) -> bool {
+        !self.a.is_work_day(date)
+    }
+
+    fn count_work_days(&self, first: &Date, last: &Date) -> usize {
+        if first > last {
+            return 0;
+        }
+        let days = (day_number(last) - day_number(first) + 1) as usize;
+        days - self.a.count_work_days(first, last)
+    }
+}
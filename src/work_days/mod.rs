@@ -0,0 +1,374 @@
+use std::sync::Arc;
+
+use crate::{PlainDate, Weekday};
+use crate::dates::day_number;
+
+mod combinators;
+mod rota;
+mod scheduled;
+mod weekday_set;
+
+pub use combinators::{AndWorkDays, ExceptWorkDays, NotWorkDays, OrWorkDays};
+pub use rota::RotaWorkDays;
+pub use scheduled::ScheduledWorkDays;
+pub use weekday_set::WeekdaySet;
+
+/// Used by [`BankHolidayCalendar`](crate::BankHolidayCalendar) to determine if a given date is work day
+/// or not (typically, but not necessarily, the weekend).
+///
+/// Implemented by closures taking a date, by [`WeekdaySet`] for patterns based on the day of the week,
+/// and by boxed or shared trait objects. Patterns can be combined using [`WorkDays::and`], [`WorkDays::or`],
+/// [`WorkDays::except`] and [`WorkDays::not`].
+///
+/// NB: When combining patterns that work with any [`PlainDate`] implementation, such as two [`WeekdaySet`]s,
+/// the date type must be named, eg. `WorkDays::<NaiveDate>::or(a, b)`; set operations like [`WeekdaySet::union`]
+/// are simpler in that case.
+///
+/// ```
+/// use govuk_bank_holidays::prelude::*;
+/// use govuk_bank_holidays::{Weekday, WeekdaySet, WorkDays};
+///
+/// fn calendar<Date: PlainDate>() -> BankHolidayCalendar<Date, impl WorkDays<Date>> {
+///     // Monday to Friday except the first Monday of each month
+///     let work_days = WeekdaySet::MON_TO_FRI
+///         .except(|date: &Date| date.weekday() == Weekday::Monday && date.day() <= 7);
+///     BankHolidayCalendar::cached_with(work_days)
+/// }
+/// ```
+pub trait WorkDays<Date: PlainDate> {
+    /// Whether given `date` is a work day or not.
+    fn is_work_day(&self, date: &Date) -> bool;
+
+    /// Count work days from `first` to `last` date, inclusive; zero if `last` precedes `first`.
+    ///
+    /// NB: the default implementation checks each day in turn so implementations
+    /// based on the day of the week should override it.
+    fn count_work_days(&self, first: &Date, last: &Date) -> usize {
+        let mut count = 0;
+        let mut date = first.clone();
+        while &date <= last {
+            if self.is_work_day(&date) {
+                count += 1;
+            }
+            date = date.next_day();
+        }
+        count
+    }
+
+    /// A date after `date` from which to continue looking for work days, skipping dates known not to be work days.
+    /// Must never skip a work day; the returned date need not itself be a work day.
+    /// Work day iterators ignore hints that do not move forwards and continue from the next day.
+    ///
+    /// NB: the default implementation moves one day at a time so implementations
+    /// with sparse work days should override it to jump ahead.
+    #[inline]
+    fn next_work_day_hint(&self, date: &Date) -> Date {
+        date.next_day()
+    }
+
+    /// A date before `date` from which to continue looking for work days, skipping dates known not to be work days;
+    /// the counterpart of [`WorkDays::next_work_day_hint`].
+    #[inline]
+    fn previous_work_day_hint(&self, date: &Date) -> Date {
+        date.previous_day()
+    }
+
+    /// Work days that are also work days according to `other`.
+    #[inline]
+    fn and<Other: WorkDays<Date>>(self, other: Other) -> AndWorkDays<Self, Other> where Self: Sized {
+        AndWorkDays::new(self, other)
+    }
+
+    /// Work days according to either this or `other`.
+    #[inline]
+    fn or<Other: WorkDays<Date>>(self, other: Other) -> OrWorkDays<Self, Other> where Self: Sized {
+        OrWorkDays::new(self, other)
+    }
+
+    /// Work days that are _not_ work days according to `other`, eg. to exclude a regular day off.
+    #[inline]
+    fn except<Other: WorkDays<Date>>(self, other: Other) -> ExceptWorkDays<Self, Other> where Self: Sized {
+        ExceptWorkDays::new(self, other)
+    }
+
+    /// Days that are not work days, and vice versa.
+    #[inline]
+    fn not(self) -> NotWorkDays<Self> where Self: Sized {
+        NotWorkDays::new(self)
+    }
+}
+
+impl<Date: PlainDate, F: Fn(&Date) -> bool> WorkDays<Date> for F {
+    #[inline]
+    fn is_work_day(&self, date: &Date) -> bool {
+        self(date)
+    }
+}
+
+/// Implement [`WorkDays`] for a pointer to a trait object by delegating every method.
+macro_rules! delegate_work_days {
+    ($($pointer:ident<$object:ty>),+ $(,)?) => {
+        $(
+            impl<'a, Date: PlainDate> WorkDays<Date> for $pointer<$object> {
+                #[inline]
+                fn is_work_day(&self, date: &Date) -> bool {
+                    (**self).is_work_day(date)
+                }
+
+                #[inline]
+                fn count_work_days(&self, first: &Date, last: &Date) -> usize {
+                    (**self).count_work_days(first, last)
+                }
+
+                #[inline]
+                fn next_work_day_hint(&self, date: &Date) -> Date {
+                    (**self).next_work_day_hint(date)
+                }
+
+                #[inline]
+                fn previous_work_day_hint(&self, date: &Date) -> Date {
+                    (**self).previous_work_day_hint(date)
+                }
+            }
+        )+
+    };
+}
+
+delegate_work_days!(
+    Box<dyn WorkDays<Date> + 'a>,
+    Box<dyn WorkDays<Date> + Send + Sync + 'a>,
+    Arc<dyn WorkDays<Date> + 'a>,
+    Arc<dyn WorkDays<Date> + Send + Sync + 'a>,
+);
+
+/// Typical working week, Monday to Friday.
+#[derive(Debug, Copy, Clone)]
+pub struct MonToFriWorkDays;
+
+impl MonToFriWorkDays {
+    #[inline]
+    fn is_work_weekday(weekday: Weekday) -> bool {
+        weekday != Weekday::Saturday && weekday != Weekday::Sunday
+    }
+}
+
+impl<Date: PlainDate> WorkDays<Date> for MonToFriWorkDays {
+    fn is_work_day(&self, date: &Date) -> bool {
+        Self::is_work_weekday(date.weekday())
+    }
+
+    fn count_work_days(&self, first: &Date, last: &Date) -> usize {
+        count_weekdays(first, last, Self::is_work_weekday)
+    }
+
+    fn next_work_day_hint(&self, date: &Date) -> Date {
+        let mut date = date.next_day();
+        while !Self::is_work_weekday(date.weekday()) {
+            date = date.next_day();
+        }
+        date
+    }
+
+    fn previous_work_day_hint(&self, date: &Date) -> Date {
+        let mut date = date.previous_day();
+        while !Self::is_work_weekday(date.weekday()) {
+            date = date.previous_day();
+        }
+        date
+    }
+}
+
+/// Count dates from `first` to `last`, inclusive, whose day of the week matches `predicate`
+/// using weekday arithmetic instead of checking each day in turn.
+pub(crate) fn count_weekdays<Date: PlainDate>(first: &Date, last: &Date, predicate: impl Fn(Weekday) -> bool) -> usize {
+    let days = day_number(last) - day_number(first) + 1;
+    if days <= 0 {
+        return 0;
+    }
+    let weekdays = Weekday::all();
+    let matching_per_week = weekdays.iter()
+        .filter(|weekday| predicate(**weekday))
+        .count();
+    let first_weekday = first.weekday() as usize - 1;
+    let matching_in_partial_week = (0..(days % 7) as usize)
+        .filter(|offset| predicate(weekdays[(first_weekday + offset) % 7]))
+        .count();
+    (days / 7) as usize * matching_per_week + matching_in_partial_week
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn work_days_january_2024<Date: PlainDate, W: WorkDays<Date>>(w: W) -> impl Iterator<Item = bool> {
+        let mut date = Date::try_from_components(2024, 1, 1)
+            .expect("date should be valid");
+        std::iter::from_fn(move || {
+            let is_weekday = w.is_work_day(&date);
+            date = date.next_day();
+            Some(is_weekday)
+        })
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn mon_to_fri<Date: PlainDate>() {
+        let work_days = work_days_january_2024::<Date, MonToFriWorkDays>(MonToFriWorkDays).take(31);
+        let expected = [true, true, true, true, true, false, false].iter().copied().cycle().take(31);
+        assert!(work_days.eq(expected));
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn count_mon_to_fri<Date: PlainDate>() {
+        struct Walked;
+
+        impl<Date: PlainDate> WorkDays<Date> for Walked {
+            fn is_work_day(&self, date: &Date) -> bool {
+                MonToFriWorkDays.is_work_day(date)
+            }
+        }
+
+        let first = Date::try_from_components(2023, 12, 1)
+            .expect("date should be valid");
+        let mut last = first.previous_day();
+        for _ in 0..60 {
+            assert_eq!(
+                MonToFriWorkDays.count_work_days(&first, &last),
+                Walked.count_work_days(&first, &last),
+                "Unexpected count of work days from {first:?} to {last:?}",
+            );
+            last = last.next_day();
+        }
+        let last = Date::try_from_components(2024, 11, 30)
+            .expect("date should be valid");
+        assert_eq!(MonToFriWorkDays.count_work_days(&first, &last), 261);
+        assert_eq!(MonToFriWorkDays.count_work_days(&last, &first), 0);
+
+        // hints skip weekends
+        let friday = Date::try_from_components(2024, 1, 5)
+            .expect("date should be valid");
+        let monday = MonToFriWorkDays.next_work_day_hint(&friday);
+        assert_eq!(monday.as_components(), (2024, 1, 8));
+        assert_eq!(MonToFriWorkDays.previous_work_day_hint(&monday), friday);
+        assert_eq!(Walked.next_work_day_hint(&friday).as_components(), (2024, 1, 6));
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub struct PartTime;
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    impl<Date: PlainDate> WorkDays<Date> for PartTime {
+        fn is_work_day(&self, date: &Date) -> bool {
+            matches!(date.weekday(), Weekday::Monday | Weekday::Tuesday | Weekday::Wednesday)
+        }
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn custom_work_days<Date: PlainDate>() {
+        let work_days = work_days_january_2024::<Date, PartTime>(PartTime).take(8);
+        let expected = [true, true, true, false, false, false, false, true];
+        assert!(work_days.eq(expected));
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn combined_work_days<Date: PlainDate>() {
+        use crate::{BankHolidayCalendar, Division};
+
+        let first_monday = |date: &Date| date.weekday() == Weekday::Monday && date.day() <= 7;
+        let work_days = work_days_january_2024::<Date, _>(WeekdaySet::MON_TO_FRI.except(first_monday)).take(15);
+        let expected = [
+            false, true, true, true, true, false, false,
+            true, true, true, true, true, false, false,
+            true,
+        ];
+        assert!(work_days.eq(expected));
+
+        let sunday = WeekdaySet::single(Weekday::Sunday);
+        let work_days = work_days_january_2024::<Date, _>(WorkDays::<Date>::and(WeekdaySet::MON_TO_SAT, PartTime));
+        assert!(work_days.take(7).eq([true, true, true, false, false, false, false]));
+        let work_days = work_days_january_2024::<Date, _>(WorkDays::<Date>::or(PartTime, sunday));
+        assert!(work_days.take(7).eq([true, true, true, false, false, false, true]));
+        let work_days = work_days_january_2024::<Date, _>(WorkDays::<Date>::not(MonToFriWorkDays)).take(7);
+        assert!(work_days.eq([false, false, false, false, false, true, true]));
+
+        let boxed: Box<dyn WorkDays<Date>> = Box::new(WeekdaySet::MON_TO_SAT);
+        let work_days = work_days_january_2024(boxed).take(7);
+        assert!(work_days.eq([true, true, true, true, true, true, false]));
+        let shared: Arc<dyn WorkDays<Date> + Send + Sync> = Arc::new(
+            WorkDays::<Date>::except(WeekdaySet::MON_TO_SAT, PartTime),
+        );
+        let work_days = work_days_january_2024(shared).take(7);
+        assert!(work_days.eq([false, false, false, true, true, true, false]));
+
+        // counts and hints agree with checking each day in turn
+        let first = Date::try_from_components(2023, 12, 1)
+            .expect("date should be valid");
+        let last = Date::try_from_components(2024, 2, 29)
+            .expect("date should be valid");
+        let walked = |work_days: &dyn WorkDays<Date>| {
+            let mut date = first.clone();
+            let mut work_days_found = Vec::new();
+            while date <= last {
+                if work_days.is_work_day(&date) {
+                    work_days_found.push(date.clone());
+                }
+                date = date.next_day();
+            }
+            work_days_found
+        };
+        let hinted = |work_days: &dyn WorkDays<Date>| {
+            let mut date = first.clone();
+            let mut work_days_found = Vec::new();
+            while date <= last {
+                if work_days.is_work_day(&date) {
+                    work_days_found.push(date.clone());
+                }
+                date = work_days.next_work_day_hint(&date);
+            }
+            work_days_found
+        };
+        let monday = WeekdaySet::single(Weekday::Monday);
+        let patterns: [Box<dyn WorkDays<Date>>; 6] = [
+            Box::new(WeekdaySet::MON_TO_SAT),
+            Box::new(WeekdaySet::EMPTY.with(Weekday::Tuesday).with(Weekday::Sunday)),
+            Box::new(WorkDays::<Date>::and(monday, WeekdaySet::single(Weekday::Wednesday))),
+            Box::new(WorkDays::<Date>::or(monday, WeekdaySet::single(Weekday::Thursday))),
+            Box::new(WeekdaySet::MON_TO_FRI.except(first_monday)),
+            Box::new(WorkDays::<Date>::not(WeekdaySet::MON_TO_FRI)),
+        ];
+        for work_days in patterns {
+            let expected = walked(work_days.as_ref());
+            assert_eq!(work_days.count_work_days(&first, &last), expected.len());
+            assert_eq!(hinted(work_days.as_ref()), expected);
+        }
+
+        // a calendar can use any of these
+        let calendar = BankHolidayCalendar::<Date, _>::cached_with(WeekdaySet::MON_TO_SAT);
+        let saturday = Date::try_from_components(2024, 1, 6)
+            .expect("date should be valid");
+        assert!(calendar.is_work_day(&saturday, Some(Division::Scotland)));
+        let calendar = BankHolidayCalendar::<Date, _>::cached_with(|date: &Date| date.day() % 2 == 1);
+        assert!(!calendar.is_work_day(&saturday, Some(Division::Scotland)));
+        let new_year = Date::try_from_components(2024, 1, 1)
+            .expect("date should be valid");
+        assert!(!calendar.is_work_day(&new_year, Some(Division::Scotland)));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        mon_to_fri::<crate::dates::chrono::DateImpl>();
+        count_mon_to_fri::<crate::dates::chrono::DateImpl>();
+        custom_work_days::<crate::dates::chrono::DateImpl>();
+        combined_work_days::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        mon_to_fri::<crate::dates::time::DateImpl>();
+        count_mon_to_fri::<crate::dates::time::DateImpl>();
+        custom_work_days::<crate::dates::time::DateImpl>();
+        combined_work_days::<crate::dates::time::DateImpl>();
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{PlainDate, WorkDays};
+
+/// Rota of on and off days repeating in a fixed cycle from a reference date, regardless of the day of the week,
+/// eg. “4 on, 4 off” or a 2-week alternating pattern.
+///
+/// NB: A rota with no on days has no work days.
+///
+/// ```
+/// use govuk_bank_holidays::prelude::*;
+/// use govuk_bank_holidays::RotaWorkDays;
+///
+/// fn next_rostered_day<Date: PlainDate>(date: Date) -> Option<Date> {
+///     let rota_start = Date::try_from_components(2024, 1, 1).unwrap();
+///     let calendar = BankHolidayCalendar::cached_with(RotaWorkDays::on_off(rota_start, 4, 4));
+///     calendar.iter_work_days_after(date, Some(Division::EnglandAndWales)).next()
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RotaWorkDays<Date: PlainDate> {
+    anchor: Date,
+    cycle: Vec<bool>,
+}
+
+impl<Date: PlainDate> RotaWorkDays<Date> {
+    /// Create a rota with a cycle of on (`true`) and off (`false`) days, starting on the `anchor` date.
+    /// The cycle repeats both after and before the `anchor` date.
+    pub fn new(anchor: Date, cycle: impl IntoIterator<Item = bool>) -> Self {
+        Self { anchor, cycle: cycle.into_iter().collect() }
+    }
+
+    /// Create a rota of `on_days` on followed by `off_days` off, starting on the `anchor` date.
+    pub fn on_off(anchor: Date, on_days: usize, off_days: usize) -> Self {
+        let on = std::iter::repeat(true).take(on_days);
+        let off = std::iter::repeat(false).take(off_days);
+        Self::new(anchor, on.chain(off))
+    }
+
+    /// Date that the cycle starts from.
+    #[inline]
+    pub fn anchor(&self) -> &Date {
+        &self.anchor
+    }
+
+    /// On (`true`) and off (`false`) days in the cycle.
+    #[inline]
+    pub fn cycle(&self) -> &[bool] {
+        &self.cycle
+    }
+
+    /// Private method to find the position of `date` in the cycle; the cycle must not be empty.
+    #[inline]
+    fn position(&self, date: &Date) -> usize {
+        date.days_since(&self.anchor).rem_euclid(self.cycle.len() as i64) as usize
+    }
+
+    /// Private method to find the number of days from `date` to the nearest on day strictly after it
+    /// (before it if not `forward`), if there are any on days.
+    fn distance_to_on_day(&self, date: &Date, forward: bool) -> Option<usize> {
+        if !self.cycle.contains(&true) {
+            return None;
+        }
+        let length = self.cycle.len();
+        let position = self.position(date);
+        (1..=length).find(|distance| {
+            let position = if forward { position + distance } else { position + length - distance };
+            self.cycle[position % length]
+        })
+    }
+}
+
+impl<Date: PlainDate> WorkDays<Date> for RotaWorkDays<Date> {
+    fn is_work_day(&self, date: &Date) -> bool {
+        !self.cycle.is_empty() && self.cycle[self.position(date)]
+    }
+
+    fn count_work_days(&self, first: &Date, last: &Date) -> usize {
+        let days = last.days_since(first) + 1;
+        if days <= 0 || self.cycle.is_empty() {
+            return 0;
+        }
+        let days = days as usize;
+        let length = self.cycle.len();
+        let on_days_per_cycle = self.cycle.iter().filter(|on| **on).count();
+        let position = self.position(first);
+        let on_days_in_partial_cycle = (0..days % length)
+            .filter(|offset| self.cycle[(position + offset) % length])
+            .count();
+        days / length * on_days_per_cycle + on_days_in_partial_cycle
+    }
+
+    fn next_work_day_hint(&self, date: &Date) -> Date {
+        self.distance_to_on_day(date, true)
+            .and_then(|distance| date.try_add_days(distance as i64).ok())
+            .unwrap_or_else(|| date.next_day())
+    }
+
+    fn previous_work_day_hint(&self, date: &Date) -> Date {
+        self.distance_to_on_day(date, false)
+            .and_then(|distance| date.try_add_days(-(distance as i64)).ok())
+            .unwrap_or_else(|| date.previous_day())
+    }
+}
+
+#[cfg(all(test, any(feature = "chrono", feature = "time")))]
+mod tests {
+    use super::*;
+    use crate::{BankHolidayCalendar, Division, Endpoints};
+
+    fn check_rota<Date: PlainDate>() {
+        let date = |year, month, day| Date::try_from_components(year, month, day).expect("date should be valid");
+        let anchor = date(2024, 1, 1);
+        let rota = RotaWorkDays::on_off(anchor.clone(), 4, 4);
+        assert_eq!(rota.cycle(), [true, true, true, true, false, false, false, false]);
+        assert_eq!(rota.anchor(), &anchor);
+
+        // cycle repeats in both directions
+        let pattern = |first: Date| {
+            let rota = &rota;
+            let mut day = first;
+            std::iter::from_fn(move || {
+                let is_work_day = rota.is_work_day(&day);
+                day = day.next_day();
+                Some(is_work_day)
+            })
+        };
+        let expected = [true, true, true, true, false, false, false, false];
+        assert!(pattern(date(2024, 1, 1)).take(24).eq(expected.iter().copied().cycle().take(24)));
+        assert!(pattern(date(2023, 12, 24)).take(8).eq(expected));
+        assert!(pattern(date(2023, 12, 26)).take(8).eq(expected.iter().copied().cycle().skip(2).take(8)));
+
+        // counts and hints agree with checking each day in turn
+        let rotas = [
+            rota.clone(),
+            RotaWorkDays::new(date(2023, 12, 20), [false, true, false, false, false, false, false, true, true, true]),
+            RotaWorkDays::on_off(anchor.clone(), 0, 3),
+            RotaWorkDays::new(anchor.clone(), []),
+        ];
+        let first = date(2023, 11, 20);
+        let last = date(2024, 2, 10);
+        for rota in rotas {
+            let mut walked = Vec::new();
+            let mut day = first.clone();
+            while day <= last {
+                if rota.is_work_day(&day) {
+                    walked.push(day.clone());
+                }
+                day = day.next_day();
+            }
+            let mut end = first.previous_day();
+            while end <= last {
+                let expected = walked.iter().filter(|work_day| *work_day <= &end).count();
+                assert_eq!(rota.count_work_days(&first, &end), expected, "Unexpected count up to {end:?} for {rota:?}");
+                end = end.next_day();
+            }
+            let mut hinted = Vec::new();
+            let mut day = first.clone();
+            while day <= last {
+                if rota.is_work_day(&day) {
+                    hinted.push(day.clone());
+                }
+                day = rota.next_work_day_hint(&day);
+            }
+            assert_eq!(hinted, walked, "Unexpected hints for {rota:?}");
+            let mut hinted = Vec::new();
+            let mut day = last.clone();
+            while day >= first {
+                if rota.is_work_day(&day) {
+                    hinted.push(day.clone());
+                }
+                day = rota.previous_work_day_hint(&day);
+            }
+            hinted.reverse();
+            assert_eq!(hinted, walked, "Unexpected hints for {rota:?}");
+        }
+
+        // next rostered day that is not a bank holiday
+        let rota = RotaWorkDays::on_off(date(2023, 12, 23), 4, 4);
+        let calendar = BankHolidayCalendar::cached_with(rota);
+        let work_days: Vec<_> = calendar.iter_work_days_after(date(2023, 12, 20), Some(Division::EnglandAndWales))
+            .take(5)
+            .map(|work_day| work_day.as_components())
+            .collect();
+        // Christmas Day, Boxing Day and New Year’s Day are skipped, weekends are not
+        assert_eq!(work_days, [(2023, 12, 23), (2023, 12, 24), (2023, 12, 31), (2024, 1, 2), (2024, 1, 3)]);
+        let (start, end) = (date(2023, 12, 1), date(2023, 12, 31));
+        let work_days = calendar.iter_work_days_between(&start, &end, Endpoints::Inclusive, None);
+        assert_eq!(work_days.count(), 13);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        check_rota::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_rota::<crate::dates::time::DateImpl>();
+    }
+}
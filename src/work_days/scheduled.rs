@@ -0,0 +1,259 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{PlainDate, WeekdaySet, WorkDays};
+use crate::dates::DateWrapper;
+
+/// Working week that changes over time, eg. when a contract changes from Monday–Friday to Monday–Thursday.
+/// Holds the days of the week that are work days from each effective date, choosing those in force on each date;
+/// the earliest entry also applies to dates before it takes effect.
+///
+/// De/serialises as a list of entries, eg. `[{"from": "2023-04-01", "weekdays": ["mon", "tue", "wed", "thu"]}]`.
+///
+/// ```
+/// use govuk_bank_holidays::prelude::*;
+/// use govuk_bank_holidays::{ScheduledWorkDays, Weekday, WeekdaySet};
+///
+/// fn calendar<Date: PlainDate>() -> BankHolidayCalendar<Date, ScheduledWorkDays<Date>> {
+///     let start = Date::try_from_components(2020, 1, 1).unwrap();
+///     let contract_change = Date::try_from_components(2023, 4, 1).unwrap();
+///     let work_days = ScheduledWorkDays::new(start, WeekdaySet::MON_TO_FRI)
+///         .with_change(contract_change, WeekdaySet::MON_TO_FRI.without(Weekday::Friday));
+///     BankHolidayCalendar::cached_with(work_days)
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ScheduledWorkDays<Date: PlainDate> {
+    /// Entries in date order, each with a unique date; never empty.
+    entries: Vec<(Date, WeekdaySet)>,
+}
+
+/// Private type used to de/serialise each entry.
+#[derive(Deserialize, Serialize)]
+#[serde(bound = "")]
+struct ScheduleEntry<Date: PlainDate> {
+    from: DateWrapper<Date>,
+    weekdays: WeekdaySet,
+}
+
+impl<Date: PlainDate> ScheduledWorkDays<Date> {
+    /// Create a schedule with given work days of the week from a date,
+    /// which also apply before it unless there is an earlier change.
+    #[inline]
+    pub fn new(from: Date, weekdays: WeekdaySet) -> Self {
+        Self { entries: vec![(from, weekdays)] }
+    }
+
+    /// Schedule with work days of the week changing from given date onwards,
+    /// replacing any entry already on that date.
+    pub fn with_change(mut self, from: Date, weekdays: WeekdaySet) -> Self {
+        match self.entries.binary_search_by(|(entry_from, _)| entry_from.cmp(&from)) {
+            Ok(index) => self.entries[index].1 = weekdays,
+            Err(index) => self.entries.insert(index, (from, weekdays)),
+        }
+        self
+    }
+
+    /// Work days of the week in force on given date.
+    #[inline]
+    pub fn weekdays_on(&self, date: &Date) -> WeekdaySet {
+        let (index, _) = self.entry_index(date);
+        self.entries[index].1
+    }
+
+    /// Iterate over entries in date order, each with the date it takes effect and the work days of the week from then.
+    pub fn entries(&self) -> impl Iterator<Item = (&Date, WeekdaySet)> {
+        self.entries.iter().map(|(from, weekdays)| (from, *weekdays))
+    }
+
+    /// Private method to find the index of the entry in force on `date`
+    /// and the date of the following entry, if any.
+    fn entry_index(&self, date: &Date) -> (usize, Option<&Date>) {
+        let index = self.entries.partition_point(|(from, _)| from <= date).saturating_sub(1);
+        (index, self.entries.get(index + 1).map(|(from, _)| from))
+    }
+}
+
+impl<Date: PlainDate> WorkDays<Date> for ScheduledWorkDays<Date> {
+    #[inline]
+    fn is_work_day(&self, date: &Date) -> bool {
+        self.weekdays_on(date).contains(date.weekday())
+    }
+
+    fn count_work_days(&self, first: &Date, last: &Date) -> usize {
+        let mut count = 0;
+        let mut first = first.clone();
+        while &first <= last {
+            let (index, next_change) = self.entry_index(&first);
+            let weekdays = self.entries[index].1;
+            match next_change {
+                Some(next_change) if next_change <= last => {
+                    count += weekdays.count_work_days(&first, &next_change.previous_day());
+                    first = next_change.clone();
+                }
+                _ => {
+                    count += weekdays.count_work_days(&first, last);
+                    break;
+                }
+            }
+        }
+        count
+    }
+
+    fn next_work_day_hint(&self, date: &Date) -> Date {
+        let next_day = date.next_day();
+        let (index, next_change) = self.entry_index(&next_day);
+        let weekdays = self.entries[index].1;
+        // never skip past a change as the new pattern could have an earlier work day
+        match (weekdays.is_empty(), next_change) {
+            (true, Some(next_change)) => next_change.clone(),
+            (true, None) => next_day,
+            (false, Some(next_change)) => weekdays.next_work_day_hint(date).min(next_change.clone()),
+            (false, None) => weekdays.next_work_day_hint(date),
+        }
+    }
+
+    fn previous_work_day_hint(&self, date: &Date) -> Date {
+        let previous_day = date.previous_day();
+        let (index, _) = self.entry_index(&previous_day);
+        let weekdays = self.entries[index].1;
+        // never skip past the start of the pattern as the earlier pattern could have a later work day;
+        // the earliest pattern also applies before it takes effect
+        let day_before_pattern = (index > 0).then(|| self.entries[index].0.previous_day());
+        match (weekdays.is_empty(), day_before_pattern) {
+            (true, Some(day_before_pattern)) => day_before_pattern,
+            (true, None) => previous_day,
+            (false, Some(day_before_pattern)) => weekdays.previous_work_day_hint(date).max(day_before_pattern),
+            (false, None) => weekdays.previous_work_day_hint(date),
+        }
+    }
+}
+
+impl<Date: PlainDate> Serialize for ScheduledWorkDays<Date> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let entries = self.entries.iter()
+            .map(|(from, weekdays)| ScheduleEntry { from: DateWrapper(from.clone()), weekdays: *weekdays });
+        serializer.collect_seq(entries)
+    }
+}
+
+impl<'de, Date: PlainDate> Deserialize<'de> for ScheduledWorkDays<Date> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let mut entries = Vec::<ScheduleEntry<Date>>::deserialize(deserializer)?.into_iter();
+        let first = entries.next()
+            .ok_or_else(|| de::Error::custom("schedule should have at least one entry"))?;
+        let schedule = entries.fold(
+            Self::new(first.from.0, first.weekdays),
+            |schedule, entry| schedule.with_change(entry.from.0, entry.weekdays),
+        );
+        Ok(schedule)
+    }
+}
+
+#[cfg(all(test, any(feature = "chrono", feature = "time")))]
+mod tests {
+    use super::*;
+    use crate::{BankHolidayCalendar, Division, Endpoints, Weekday};
+
+    fn check_schedule<Date: PlainDate>() {
+        let date = |year, month, day| Date::try_from_components(year, month, day).expect("date should be valid");
+        let mon_to_thu = WeekdaySet::MON_TO_FRI.without(Weekday::Friday);
+        let schedule = ScheduledWorkDays::new(date(2023, 4, 1), mon_to_thu)
+            .with_change(date(2020, 1, 1), WeekdaySet::MON_TO_FRI)
+            .with_change(date(2023, 6, 1), WeekdaySet::EMPTY)
+            .with_change(date(2023, 7, 1), WeekdaySet::single(Weekday::Saturday))
+            .with_change(date(2023, 6, 1), WeekdaySet::single(Weekday::Sunday));
+        assert_eq!(schedule.entries().count(), 4);
+        assert_eq!(schedule.weekdays_on(&date(2019, 1, 1)), WeekdaySet::MON_TO_FRI);
+        assert_eq!(schedule.weekdays_on(&date(2023, 3, 31)), WeekdaySet::MON_TO_FRI);
+        assert_eq!(schedule.weekdays_on(&date(2023, 4, 1)), mon_to_thu);
+        assert_eq!(schedule.weekdays_on(&date(2023, 6, 30)), WeekdaySet::single(Weekday::Sunday));
+        assert_eq!(schedule.weekdays_on(&date(2030, 1, 1)), WeekdaySet::single(Weekday::Saturday));
+        let friday = date(2023, 3, 31);
+        assert!(schedule.is_work_day(&friday));
+        assert!(!schedule.is_work_day(&date(2023, 4, 7)));
+
+        // counts and hints across changes agree with checking each day in turn
+        let first = date(2023, 3, 1);
+        let last = date(2023, 8, 31);
+        let mut walked = Vec::new();
+        let mut day = first.clone();
+        while day <= last {
+            if schedule.is_work_day(&day) {
+                walked.push(day.clone());
+            }
+            day = day.next_day();
+        }
+        let mut end = first.clone();
+        while end <= last {
+            let expected = walked.iter().filter(|work_day| *work_day <= &end).count();
+            assert_eq!(schedule.count_work_days(&first, &end), expected, "Unexpected count up to {end:?}");
+            end = end.next_day();
+        }
+        let mut hinted = Vec::new();
+        let mut day = first.clone();
+        while day <= last {
+            if schedule.is_work_day(&day) {
+                hinted.push(day.clone());
+            }
+            day = schedule.next_work_day_hint(&day);
+        }
+        assert_eq!(hinted, walked);
+        let mut hinted = Vec::new();
+        let mut day = last.clone();
+        while day >= first {
+            if schedule.is_work_day(&day) {
+                hinted.push(day.clone());
+            }
+            day = schedule.previous_work_day_hint(&day);
+        }
+        hinted.reverse();
+        assert_eq!(hinted, walked);
+
+        // calendars skip bank holidays across changes
+        let calendar = BankHolidayCalendar::cached_with(schedule.clone());
+        let thursday = date(2023, 4, 6);
+        let work_days: Vec<_> = calendar.iter_work_days_after(date(2023, 3, 30), Some(Division::EnglandAndWales))
+            .take(4)
+            .map(|work_day| work_day.as_components())
+            .collect();
+        // 7th April was Good Friday and 10th April Easter Monday
+        assert_eq!(work_days, [(2023, 3, 31), (2023, 4, 3), (2023, 4, 4), (2023, 4, 5)]);
+        assert_eq!(calendar.iter_work_days_before(thursday, None).next(), Some(date(2023, 4, 5)));
+        let work_days: Vec<_> = calendar.iter_work_days_after(date(2023, 5, 31), None)
+            .take(3)
+            .map(|work_day| work_day.as_components())
+            .collect();
+        assert_eq!(work_days, [(2023, 6, 4), (2023, 6, 11), (2023, 6, 18)]);
+        let count = calendar.count_work_days_between(&first, &last, Endpoints::Inclusive, None);
+        assert_eq!(count, calendar.iter_work_days_between(&first, &last, Endpoints::Inclusive, None).count());
+
+        // de/serialises as a list of entries
+        let serialised = serde_json::to_value(&schedule).expect("schedule should serialise");
+        assert_eq!(serialised[0], serde_json::json!({
+            "from": "2020-01-01",
+            "weekdays": ["monday", "tuesday", "wednesday", "thursday", "friday"],
+        }));
+        let deserialised: ScheduledWorkDays<Date> = serde_json::from_value(serialised)
+            .expect("schedule should deserialise");
+        assert_eq!(deserialised, schedule);
+        let deserialised: ScheduledWorkDays<Date> = serde_json::from_str(r#"[
+            {"from": "2023-04-01", "weekdays": ["mon", "tue", "wed", "thu"]},
+            {"from": "2020-01-01", "weekdays": ["mon", "tue", "wed", "thu", "fri"]}
+        ]"#).expect("schedule should deserialise");
+        assert_eq!(deserialised.weekdays_on(&friday), WeekdaySet::MON_TO_FRI);
+        assert!(serde_json::from_str::<ScheduledWorkDays<Date>>("[]").is_err());
+        assert!(serde_json::from_str::<ScheduledWorkDays<Date>>(r#"[{"from": "2023-02-30", "weekdays": []}]"#).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        check_schedule::<crate::dates::chrono::DateImpl>();
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_schedule::<crate::dates::time::DateImpl>();
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{PlainDate, Weekday, WorkDays};
+use crate::work_days::count_weekdays;
+
+/// Set of days of the week, usable as [`WorkDays`] for any working week based on the day of the week.
+/// De/serialises as a list of days, eg. `["monday", "tuesday"]`.
+///
+/// ```
+/// use govuk_bank_holidays::{Weekday, WeekdaySet};
+///
+/// let mon_to_sat = WeekdaySet::MON_TO_FRI.with(Weekday::Saturday);
+/// assert_eq!(mon_to_sat, WeekdaySet::from_iter([
+///     Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday, Weekday::Friday, Weekday::Saturday,
+/// ]));
+/// assert_eq!(mon_to_sat.len(), 6);
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(from = "Vec<Weekday>", into = "Vec<Weekday>")]
+pub struct WeekdaySet(u8);
+
+impl WeekdaySet {
+    /// No days of the week.
+    pub const EMPTY: Self = Self(0);
+    /// Monday to Friday, like [`MonToFriWorkDays`](crate::MonToFriWorkDays).
+    pub const MON_TO_FRI: Self = Self(0b0011111);
+    /// Monday to Saturday.
+    pub const MON_TO_SAT: Self = Self(0b0111111);
+    /// Every day of the week.
+    pub const ALL: Self = Self(0b1111111);
+
+    #[inline]
+    const fn bit(weekday: Weekday) -> u8 {
+        1 << (weekday as u8 - 1)
+    }
+
+    /// Set of only given day of the week.
+    #[inline]
+    pub const fn single(weekday: Weekday) -> Self {
+        Self(Self::bit(weekday))
+    }
+
+    /// Set with given day of the week added.
+    #[inline]
+    pub const fn with(self, weekday: Weekday) -> Self {
+        Self(self.0 | Self::bit(weekday))
+    }
+
+    /// Set with given day of the week removed.
+    #[inline]
+    pub const fn without(self, weekday: Weekday) -> Self {
+        Self(self.0 & !Self::bit(weekday))
+    }
+
+    /// Whether given day of the week is in the set.
+    #[inline]
+    pub const fn contains(self, weekday: Weekday) -> bool {
+        self.0 & Self::bit(weekday) != 0
+    }
+
+    /// Days of the week in either set.
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Days of the week in both sets.
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Days of the week not in the set.
+    #[inline]
+    pub const fn complement(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+
+    /// Number of days of the week in the set.
+    #[inline]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Whether the set has no days of the week.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterate over days of the week in the set, starting with Monday.
+    pub fn iter(self) -> impl Iterator<Item = Weekday> {
+        Weekday::all().into_iter().filter(move |weekday| self.contains(*weekday))
+    }
+}
+
+impl fmt::Debug for WeekdaySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<Weekday> for WeekdaySet {
+    fn from_iter<T: IntoIterator<Item = Weekday>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with)
+    }
+}
+
+impl From<Weekday> for WeekdaySet {
+    #[inline]
+    fn from(weekday: Weekday) -> Self {
+        Self::single(weekday)
+    }
+}
+
+impl From<Vec<Weekday>> for WeekdaySet {
+    #[inline]
+    fn from(weekdays: Vec<Weekday>) -> Self {
+        weekdays.into_iter().collect()
+    }
+}
+
+impl From<WeekdaySet> for Vec<Weekday> {
+    #[inline]
+    fn from(weekdays: WeekdaySet) -> Self {
+        weekdays.iter().collect()
+    }
+}
+
+impl<Date: PlainDate> WorkDays<Date> for WeekdaySet {
+    #[inline]
+    fn is_work_day(&self, date: &Date) -> bool {
+        self.contains(date.weekday())
+    }
+
+    fn count_work_days(&self, first: &Date, last: &Date) -> usize {
+        count_weekdays(first, last, |weekday| self.contains(weekday))
+    }
+
+    fn next_work_day_hint(&self, date: &Date) -> Date {
+        let mut date = date.next_day();
+        // an empty set has no work days to skip to
+        if !self.is_empty() {
+            while !self.contains(date.weekday()) {
+                date = date.next_day();
+            }
+        }
+        date
+    }
+
+    fn previous_work_day_hint(&self, date: &Date) -> Date {
+        let mut date = date.previous_day();
+        if !self.is_empty() {
+            while !self.contains(date.weekday()) {
+                date = date.previous_day();
+            }
+        }
+        date
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        assert!(WeekdaySet::EMPTY.is_empty());
+        assert_eq!(WeekdaySet::ALL.len(), 7);
+        assert_eq!(WeekdaySet::ALL.iter().collect::<Vec<_>>(), Weekday::all());
+        assert_eq!(WeekdaySet::MON_TO_FRI.complement(), WeekdaySet::from_iter([Weekday::Saturday, Weekday::Sunday]));
+        assert_eq!(WeekdaySet::MON_TO_FRI.with(Weekday::Saturday), WeekdaySet::MON_TO_SAT);
+        assert_eq!(WeekdaySet::MON_TO_SAT.without(Weekday::Saturday), WeekdaySet::MON_TO_FRI);
+        assert_eq!(
+            WeekdaySet::MON_TO_FRI.intersection(WeekdaySet::MON_TO_SAT.complement().with(Weekday::Monday)),
+            WeekdaySet::single(Weekday::Monday),
+        );
+        assert_eq!(WeekdaySet::MON_TO_FRI.union(WeekdaySet::MON_TO_SAT.complement()).len(), 6);
+        assert!(!WeekdaySet::MON_TO_FRI.contains(Weekday::Sunday));
+        assert_eq!(format!("{:?}", WeekdaySet::from(Weekday::Tuesday)), "{Tuesday}");
+    }
+
+    #[test]
+    fn serde() {
+        let serialised = serde_json::to_string(&WeekdaySet::MON_TO_FRI.without(Weekday::Wednesday))
+            .expect("set should serialise");
+        assert_eq!(serialised, r#"["monday","tuesday","thursday","friday"]"#);
+        let deserialised: WeekdaySet = serde_json::from_str(r#"["sun", "saturday", "sun"]"#)
+            .expect("set should deserialise");
+        assert_eq!(deserialised, WeekdaySet::MON_TO_SAT.complement().with(Weekday::Saturday));
+        assert!(serde_json::from_str::<WeekdaySet>(r#"["someday"]"#).is_err());
+    }
+}